@@ -41,6 +41,36 @@ pub struct CalendarEventItem {
     pub location: Option<String>,
     pub calendar_name: String,
     pub is_all_day: bool,
+    /// A conferencing URL (Zoom/Google Meet/Teams) found in the event's
+    /// location or description, if any — fed to `MeetingDetector` so it can
+    /// pre-arm the meeting a few minutes before start.
+    #[serde(default)]
+    pub meeting_url: Option<String>,
+}
+
+/// Known video-conferencing hosts worth pre-arming meeting detection for.
+const MEETING_URL_HOSTS: &[&str] = &["zoom.us", "meet.google.com", "teams.microsoft.com"];
+
+/// Scan a handful of free-text calendar fields (location, description, the
+/// non-standard `X-GOOGLE-CONFERENCE` property some feeds set) for the first
+/// URL pointing at a known conferencing host.
+pub(crate) fn extract_meeting_url(fields: &[Option<&str>]) -> Option<String> {
+    fields
+        .iter()
+        .flatten()
+        .find_map(|text| find_meeting_url_in_text(text))
+}
+
+fn find_meeting_url_in_text(text: &str) -> Option<String> {
+    text.split_whitespace().find_map(|token| {
+        let token = token.trim_matches(|c: char| !c.is_ascii_alphanumeric() && !"/.:_-?=&%".contains(c));
+        let is_url = token.starts_with("http://") || token.starts_with("https://");
+        if is_url && MEETING_URL_HOSTS.iter().any(|host| token.contains(host)) {
+            Some(token.to_string())
+        } else {
+            None
+        }
+    })
 }
 
 // ─── Commands ───────────────────────────────────────────────────────────────
@@ -339,6 +369,10 @@ fn calendar_event_to_item(
 ) -> CalendarEventItem {
     let start_display = event.start_local.format("%-I:%M %p").to_string();
     let end_display = event.end_local.format("%-I:%M %p").to_string();
+    // EventKit doesn't expose event notes/description through
+    // `screenpipe-integrations`, so pre-arm detection only sees location-based
+    // conferencing links on macOS/Windows for now.
+    let meeting_url = extract_meeting_url(&[event.location.as_deref()]);
 
     CalendarEventItem {
         id: event.id,
@@ -351,6 +385,7 @@ fn calendar_event_to_item(
         location: event.location,
         calendar_name: event.calendar_name,
         is_all_day: event.is_all_day,
+        meeting_url,
     }
 }
 
@@ -360,6 +395,8 @@ fn calendar_event_to_item_win(
 ) -> CalendarEventItem {
     let start_display = event.start_local.format("%-I:%M %p").to_string();
     let end_display = event.end_local.format("%-I:%M %p").to_string();
+    // Same location-only limitation as the macOS path above.
+    let meeting_url = extract_meeting_url(&[event.location.as_deref()]);
 
     CalendarEventItem {
         id: event.id,
@@ -372,5 +409,6 @@ fn calendar_event_to_item_win(
         location: event.location,
         calendar_name: event.calendar_name,
         is_all_day: event.is_all_day,
+        meeting_url,
     }
 }
@@ -1046,6 +1046,67 @@ pub async fn copy_frame_to_clipboard(frame_id: i64) -> Result<(), String> {
     Ok(())
 }
 
+/// Mirror of `screenpipe_server::hot_frame_cache::InstantSearchHit`. Defined
+/// here (rather than reused directly) because the value crosses the
+/// server/app boundary as JSON over HTTP, not as a linked Rust type, and
+/// `specta::Type` can't be implemented for a foreign type from this crate.
+#[derive(serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct InstantSearchHit {
+    pub kind: String,
+    pub timestamp: String,
+    pub app_name: Option<String>,
+    pub window_name: Option<String>,
+    pub device_name: String,
+    pub snippet: String,
+    pub frame_id: Option<i64>,
+    pub audio_chunk_id: Option<i64>,
+}
+
+/// Query the search-overlay's instant results: answers from the local
+/// server's in-memory hot cache only (today's frames + audio), so it stays
+/// fast enough to filter as the user types the global-search shortcut opens.
+#[tauri::command]
+#[specta::specta]
+pub async fn instant_search(query: String, limit: Option<usize>) -> Result<Vec<InstantSearchHit>, String> {
+    let limit = limit.unwrap_or(20).to_string();
+    reqwest::Client::new()
+        .get("http://127.0.0.1:3030/search/instant")
+        .query(&[("q", query.as_str()), ("limit", limit.as_str())])
+        .send()
+        .await
+        .map_err(|e| format!("failed to reach local server: {}", e))?
+        .json::<Vec<InstantSearchHit>>()
+        .await
+        .map_err(|e| format!("failed to parse instant search response: {}", e))
+}
+
+/// Mirror of `screenpipe_server::doctor::DoctorCheck` — crosses the
+/// server/app boundary as JSON over HTTP, so it needs its own
+/// `specta::Type` impl rather than reusing the foreign type directly.
+#[derive(serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct DoctorCheck {
+    pub name: String,
+    pub status: String,
+    pub message: String,
+    pub remediation: Option<String>,
+}
+
+/// Run the onboarding diagnostics (permissions, audio devices, disk space,
+/// db integrity, model presence, ffmpeg availability) against the local
+/// server and return the pass/warn/fail results shown during onboarding.
+#[tauri::command]
+#[specta::specta]
+pub async fn run_doctor() -> Result<Vec<DoctorCheck>, String> {
+    reqwest::Client::new()
+        .get("http://127.0.0.1:3030/doctor")
+        .send()
+        .await
+        .map_err(|e| format!("failed to reach local server: {}", e))?
+        .json::<Vec<DoctorCheck>>()
+        .await
+        .map_err(|e| format!("failed to parse doctor response: {}", e))
+}
+
 /// Copy a frame deeplink (screenpipe://frame/N) to clipboard. Native API only.
 #[tauri::command]
 #[specta::specta]
@@ -265,8 +265,18 @@ pub async fn start_embedded_server(
     if ui_enabled {
         let ui_config = config.to_ui_recorder_config();
         let db_clone = db.clone();
+        let ui_events_journal_dir = data_path.join(".ui_events_journal");
+        let hot_frame_cache_for_ui = hot_frame_cache.clone();
         tokio::spawn(async move {
-            match start_ui_recording(db_clone, ui_config, capture_trigger_tx).await {
+            match start_ui_recording(
+                db_clone,
+                ui_config,
+                capture_trigger_tx,
+                ui_events_journal_dir,
+                Some(hot_frame_cache_for_ui),
+            )
+            .await
+            {
                 Ok(handle) => {
                     info!("UI event recording started successfully");
                     // Keep the handle alive - don't drop it or UI recording stops
@@ -59,7 +59,9 @@ mod space_monitor;
 mod sync;
 mod reminders;
 mod calendar;
+mod caldav;
 mod ics_calendar;
+mod privacy_pause;
 mod pi;
 mod embedded_server;
 mod suggestions;
@@ -1202,6 +1204,8 @@ async fn main() {
                 commands::set_onboarding_step,
                 commands::show_onboarding_window,
                 commands::open_search_window,
+                commands::instant_search,
+                commands::run_doctor,
                 // Shortcut reminder commands
                 commands::show_shortcut_reminder,
                 commands::hide_shortcut_reminder,
@@ -1262,6 +1266,11 @@ async fn main() {
                 ics_calendar::ics_calendar_save_entries,
                 ics_calendar::ics_calendar_test_url,
                 ics_calendar::ics_calendar_get_upcoming,
+                ics_calendar::ics_calendar_refresh_now,
+                // Privacy pause
+                privacy_pause::privacy_pause_start,
+                privacy_pause::privacy_pause_resume,
+                privacy_pause::privacy_pause_status,
                 // Voice training
                 voice_training::train_voice,
                 // Suggestions
@@ -1422,6 +1431,8 @@ async fn main() {
             commands::set_onboarding_step,
             commands::show_onboarding_window,
             commands::open_search_window,
+            commands::instant_search,
+            commands::run_doctor,
             // Shortcut reminder commands
             commands::show_shortcut_reminder,
             commands::hide_shortcut_reminder,
@@ -1486,6 +1497,11 @@ async fn main() {
             ics_calendar::ics_calendar_save_entries,
             ics_calendar::ics_calendar_test_url,
             ics_calendar::ics_calendar_get_upcoming,
+            ics_calendar::ics_calendar_refresh_now,
+            // Privacy pause
+            privacy_pause::privacy_pause_start,
+            privacy_pause::privacy_pause_resume,
+            privacy_pause::privacy_pause_status,
             // Rollback commands
             commands::rollback_to_version,
             // OCR commands
@@ -2136,6 +2152,9 @@ async fn main() {
                 ics_calendar::start_ics_calendar_poller(ics_app_handle).await;
             });
 
+            // Start privacy-pause auto-resume task (clears expired pauses, notifies)
+            privacy_pause::start_privacy_pause_auto_resume(app_handle.clone());
+
             // Auto-start cloud sync if it was enabled
             let app_handle_clone = app_handle.clone();
             let sync_state = app_handle.state::<sync::SyncState>();
@@ -4,6 +4,7 @@
 
 use crate::commands::show_main_window;
 use crate::health::{get_recording_info, get_recording_status, DeviceKind, RecordingStatus};
+use crate::privacy_pause::CapturePipeline;
 use crate::recording::RecordingState;
 use crate::store::{get_store, OnboardingStore};
 use crate::updates::{is_enterprise_build, is_source_build};
@@ -13,6 +14,7 @@ use once_cell::sync::Lazy;
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Mutex;
+use tauri::menu::SubmenuBuilder;
 use tauri::tray::{TrayIcon, TrayIconBuilder};
 use tauri::Emitter;
 use tauri::{
@@ -20,6 +22,7 @@ use tauri::{
     AppHandle, Manager, Wry,
 };
 use tauri_plugin_dialog::{DialogExt, MessageDialogButtons};
+use tauri_plugin_notification::NotificationExt;
 use tauri_plugin_opener::OpenerExt;
 
 use tracing::{debug, error, info};
@@ -61,6 +64,8 @@ struct MenuState {
     has_permission_issue: bool,
     /// Device names + active status for change detection
     devices: Vec<(String, bool)>,
+    /// Whether any capture pipeline is currently privacy-paused
+    any_pipeline_paused: bool,
 }
 
 pub fn setup_tray(app: &AppHandle, update_item: Option<&tauri::menu::MenuItem<Wry>>) -> Result<()> {
@@ -376,6 +381,29 @@ fn create_dynamic_menu(
             .item(&MenuItemBuilder::with_id("stop_recording", "Stop recording").build(app)?);
     }
 
+    // Quick privacy pause — pause every capture pipeline (screen, mic,
+    // system audio, UI events) at once for a chosen duration. Finer-grained
+    // per-pipeline control lives in settings via the privacy_pause_* commands.
+    {
+        let any_paused = !screenpipe_audio::privacy_pause::active_pauses().is_empty();
+        let mut pause_submenu = SubmenuBuilder::new(app, "Pause capture");
+        if any_paused {
+            pause_submenu = pause_submenu
+                .item(&MenuItemBuilder::with_id("privacy_resume", "Resume capture").build(app)?)
+                .item(&PredefinedMenuItem::separator(app)?);
+        }
+        pause_submenu = pause_submenu
+            .item(&MenuItemBuilder::with_id("privacy_pause_15m", "Pause all for 15 min").build(app)?)
+            .item(&MenuItemBuilder::with_id("privacy_pause_1h", "Pause all for 1 hour").build(app)?)
+            .item(
+                &MenuItemBuilder::with_id("privacy_pause_indefinite", "Pause all until resumed")
+                    .build(app)?,
+            );
+        menu_builder = menu_builder
+            .item(&PredefinedMenuItem::separator(app)?)
+            .item(&pause_submenu.build()?);
+    }
+
     // Help and quit
     menu_builder = menu_builder
         .item(&PredefinedMenuItem::separator(app)?);
@@ -443,6 +471,15 @@ fn handle_menu_event(app_handle: &AppHandle, event: tauri::menu::MenuEvent) {
         "stop_recording" => {
             let _ = app_handle.emit("shortcut-stop-recording", ());
         }
+        "privacy_pause_15m" => start_quick_privacy_pause(app_handle, Some(15 * 60)),
+        "privacy_pause_1h" => start_quick_privacy_pause(app_handle, Some(60 * 60)),
+        "privacy_pause_indefinite" => start_quick_privacy_pause(app_handle, None),
+        "privacy_resume" => {
+            for pipeline in CapturePipeline::ALL {
+                screenpipe_audio::privacy_pause::resume(pipeline.into());
+            }
+            info!("privacy pause: resumed all pipelines from tray");
+        }
         "fix_permissions" => {
             let app = app_handle.clone();
             let _ = app_handle.run_on_main_thread(move || {
@@ -588,6 +625,7 @@ async fn update_menu_if_needed(
             .iter()
             .map(|d| (d.name.clone(), d.active))
             .collect(),
+        any_pipeline_paused: !screenpipe_audio::privacy_pause::active_pauses().is_empty(),
     };
 
     // Compare with last state (poison-safe: run handler must not panic)
@@ -675,6 +713,37 @@ pub fn setup_tray_menu_updater(app: AppHandle, update_item: &tauri::menu::MenuIt
     });
 }
 
+/// Pause every capture pipeline (screen, mic, system audio, UI events) at
+/// once — the tray's "quick" privacy toggle. `duration_secs` of `None` means
+/// paused until manually resumed.
+fn start_quick_privacy_pause(app_handle: &AppHandle, duration_secs: Option<u64>) {
+    let until_ms = match duration_secs {
+        Some(secs) => chrono::Utc::now().timestamp_millis() + (secs as i64) * 1000,
+        None => screenpipe_audio::privacy_pause::PAUSE_INDEFINITE,
+    };
+    for pipeline in CapturePipeline::ALL {
+        screenpipe_audio::privacy_pause::pause(pipeline.into(), until_ms);
+    }
+    info!(
+        "privacy pause: paused all pipelines from tray (until_ms={})",
+        until_ms
+    );
+
+    let app = app_handle.clone();
+    tauri::async_runtime::spawn(async move {
+        let _ = app
+            .notification()
+            .builder()
+            .title("screenpipe capture paused")
+            .body(if until_ms == screenpipe_audio::privacy_pause::PAUSE_INDEFINITE {
+                "screen, microphone, system audio, and UI-event capture paused until resumed"
+            } else {
+                "screen, microphone, system audio, and UI-event capture paused"
+            })
+            .show();
+    });
+}
+
 fn format_shortcut(shortcut: &str) -> String {
     // Format shortcut for display in tray menu
     // macOS convention: ⌘ (Command) → ⌃ (Control) → ⌥ (Option) → ⇧ (Shift) → Key
@@ -185,6 +185,7 @@ impl MacosTreeWalker {
 }
 
 impl TreeWalkerPlatform for MacosTreeWalker {
+    #[tracing::instrument(skip(self))]
     fn walk_focused_window(&self) -> Result<Option<TreeSnapshot>> {
         // Wrap in autorelease pool — cidre AX/NS APIs create autoreleased
         // ObjC objects. Without this, objects accumulate on the tokio
@@ -5,6 +5,7 @@
 use anyhow::Result;
 use std::{collections::HashSet, env, path::PathBuf, sync::Arc, time::Duration};
 
+use screenpipe_core::text_filters::TranscriptFilterConfig;
 use screenpipe_core::Language;
 use screenpipe_db::DatabaseManager;
 
@@ -51,6 +52,10 @@ pub struct AudioManagerOptions {
     pub output_path: Option<PathBuf>,
     /// Enable PII removal from audio transcriptions
     pub use_pii_removal: bool,
+    /// Optional profanity masking / filler-word stripping, applied before PII removal.
+    /// The raw transcript is preserved in `audio_transcriptions.raw_transcription` when
+    /// the filter actually changes the text, so the redaction is reversible.
+    pub transcript_filter: TranscriptFilterConfig,
     /// When true, automatically follow system default audio devices
     /// and switch when the system default changes (e.g., device plug/unplug)
     pub use_system_default_audio: bool,
@@ -62,6 +67,14 @@ pub struct AudioManagerOptions {
     pub meeting_detector: Option<Arc<MeetingDetector>>,
     /// Custom vocabulary entries for transcription biasing and word replacement.
     pub vocabulary: Vec<VocabularyEntry>,
+    /// Devices (by name, matching `enabled_devices`) with noise suppression
+    /// enabled — applied before VAD/transcription. Off by default: it costs
+    /// extra CPU per chunk and most devices don't need it.
+    pub denoise_devices: HashSet<String>,
+    /// Devices (by name, matching `enabled_devices`) recorded as two
+    /// per-channel virtual devices instead of one downmixed-to-mono stream.
+    /// See `crate::core::device::ChannelSelector`.
+    pub channel_split_devices: HashSet<String>,
 }
 
 impl Default for AudioManagerOptions {
@@ -86,10 +99,13 @@ impl Default for AudioManagerOptions {
             deepgram_url,
             deepgram_websocket_url,
             use_pii_removal: false,
+            transcript_filter: TranscriptFilterConfig::default(),
             use_system_default_audio: true,
             transcription_mode: TranscriptionMode::default(),
             meeting_detector: None,
             vocabulary: vec![],
+            denoise_devices: HashSet::new(),
+            channel_split_devices: HashSet::new(),
         }
     }
 }
@@ -171,6 +187,11 @@ impl AudioManagerBuilder {
         self
     }
 
+    pub fn transcript_filter(mut self, transcript_filter: TranscriptFilterConfig) -> Self {
+        self.options.transcript_filter = transcript_filter;
+        self
+    }
+
     pub fn use_system_default_audio(mut self, use_system_default_audio: bool) -> Self {
         self.options.use_system_default_audio = use_system_default_audio;
         self
@@ -191,6 +212,21 @@ impl AudioManagerBuilder {
         self
     }
 
+    /// Devices (by name) to run through the noise suppression stage before
+    /// VAD/transcription. See `crate::denoise`.
+    pub fn denoise_devices(mut self, denoise_devices: Vec<String>) -> Self {
+        self.options.denoise_devices = HashSet::from_iter(denoise_devices);
+        self
+    }
+
+    /// Devices (by name) to record as two per-channel virtual devices
+    /// (left/right) instead of one downmixed-to-mono stream. See
+    /// `crate::core::device::ChannelSelector`.
+    pub fn channel_split_devices(mut self, channel_split_devices: Vec<String>) -> Self {
+        self.options.channel_split_devices = HashSet::from_iter(channel_split_devices);
+        self
+    }
+
     pub async fn build(&mut self, db: Arc<DatabaseManager>) -> Result<AudioManager> {
         self.validate_options()?;
         let options = &mut self.options;
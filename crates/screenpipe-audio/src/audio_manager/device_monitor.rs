@@ -296,6 +296,32 @@ pub async fn start_device_monitor(
                 // Check central handler health (audio-receiver + transcription-receiver)
                 if !central_restart_exhausted.load(std::sync::atomic::Ordering::Relaxed) {
                     let result = audio_manager.check_and_restart_central_handlers().await;
+                    if result.recording_restarted {
+                        let _ = screenpipe_events::send_event(
+                            "pipeline_restarted",
+                            serde_json::json!({
+                                "pipeline": "audio",
+                                "component": "recording_handler",
+                                "reason": result
+                                    .recording_error
+                                    .clone()
+                                    .unwrap_or_else(|| "recording handler task died".to_string()),
+                            }),
+                        );
+                    }
+                    if result.transcription_restarted {
+                        let _ = screenpipe_events::send_event(
+                            "pipeline_restarted",
+                            serde_json::json!({
+                                "pipeline": "audio",
+                                "component": "transcription_handler",
+                                "reason": result
+                                    .transcription_error
+                                    .clone()
+                                    .unwrap_or_else(|| "transcription handler task died".to_string()),
+                            }),
+                        );
+                    }
                     if result.recording_restarted || result.transcription_restarted {
                         let now = Instant::now();
                         central_restart_times.push(now);
@@ -353,6 +379,15 @@ pub async fn start_device_monitor(
                         }
                     };
 
+                    // Channel-split virtual devices (see `AudioDevice::channel_virtual`)
+                    // never appear in `currently_available_devices` — they aren't real,
+                    // separately enumerable hardware. Their lifecycle is tied to their
+                    // base device's start_device/stop_device call, so skip them here
+                    // to avoid mistaking "not a real device" for "disconnected".
+                    if device.is_channel_virtual() {
+                        continue;
+                    }
+
                     if device_manager.is_running(&device)
                         && !currently_available_devices.contains(&device)
                     {
@@ -26,13 +26,14 @@ use screenpipe_db::DatabaseManager;
 use super::{start_device_monitor, stop_device_monitor, AudioManagerOptions, TranscriptionMode};
 use crate::{
     core::{
-        device::{parse_audio_device, AudioDevice},
+        device::{parse_audio_device, AudioDevice, DeviceType},
         engine::AudioTranscriptionEngine,
         record_and_transcribe,
     },
     device::device_manager::DeviceManager,
     meeting_detector::MeetingDetector,
     metrics::AudioPipelineMetrics,
+    privacy_pause::{self, CapturePipeline},
     segmentation::segmentation_manager::SegmentationManager,
     transcription::{
         deepgram::streaming::stream_transcription_deepgram,
@@ -168,6 +169,41 @@ impl AudioManager {
 
     async fn start_internal(&self) -> Result<()> {
         *self.status.write().await = AudioManagerStatus::Running;
+
+        // Recover audio spilled by a previous, uncleanly terminated process before
+        // starting new recordings. The spill file is already valid audio (see
+        // core::spill) — it just never made it into the DB as an audio_chunk, so the
+        // normal orphaned-chunk reconciliation sweep never saw it.
+        if let Some(output_path) = self.options.read().await.output_path.clone() {
+            for orphan in crate::core::spill::find_orphaned_spills(&output_path) {
+                let device_name = orphan.device_name.clone();
+                match crate::core::spill::adopt_spill(&orphan) {
+                    Ok(adopted_path) => {
+                        let capture_ts =
+                            chrono::DateTime::from_timestamp(orphan.capture_timestamp as i64, 0);
+                        match self
+                            .db
+                            .insert_audio_chunk(&adopted_path.to_string_lossy(), capture_ts)
+                            .await
+                        {
+                            Ok(id) => info!(
+                                "recovered orphaned audio spill for {} as chunk {}",
+                                device_name, id
+                            ),
+                            Err(e) => error!(
+                                "failed to insert recovered audio spill for {}: {}",
+                                device_name, e
+                            ),
+                        }
+                    }
+                    Err(e) => error!(
+                        "failed to adopt orphaned audio spill for {}: {}",
+                        device_name, e
+                    ),
+                }
+            }
+        }
+
         let mut transcription_receiver_handle = self.transcription_receiver_handle.write().await;
         *transcription_receiver_handle = Some(self.start_transcription_receiver_handler().await?);
 
@@ -180,6 +216,7 @@ impl AudioManager {
             let db = self.db.clone();
             let whisper_ctx_ref = self.whisper_context.clone();
             let options = self.options.clone();
+            let metrics = self.metrics.clone();
             tokio::spawn(async move {
                 // Wait for Whisper model to load + initial recordings
                 tokio::time::sleep(Duration::from_secs(120)).await;
@@ -192,7 +229,7 @@ impl AudioManager {
                         let vocab = opts.vocabulary.clone();
                         drop(opts);
                         let count = super::reconciliation::reconcile_untranscribed(
-                            &db, ctx, engine, key, langs, &vocab,
+                            &db, ctx, engine, key, langs, &vocab, metrics.clone(),
                         )
                         .await;
                         if count > 0 {
@@ -264,21 +301,39 @@ impl AudioManager {
             Err(_) => return Err(anyhow!("Device {} not found", device_name)),
         };
 
+        // A channel-split device was started as an L/R virtual pair, not
+        // itself — stop the virtual pair instead of the (never-running) base.
+        if self.channel_split_devices().await.contains(device_name) {
+            let mut last_result = Ok(());
+            for channel in [
+                crate::core::device::ChannelSelector::Left,
+                crate::core::device::ChannelSelector::Right,
+            ] {
+                let virtual_device = device.channel_virtual(channel);
+                last_result = self.stop_single_device(&virtual_device).await;
+            }
+            return last_result;
+        }
+
+        self.stop_single_device(&device).await
+    }
+
+    async fn stop_single_device(&self, device: &AudioDevice) -> Result<()> {
         self.options
             .write()
             .await
             .enabled_devices
-            .remove(device_name);
+            .remove(&device.to_string());
 
-        self.device_manager.stop_device(&device).await?;
+        self.device_manager.stop_device(device).await?;
 
-        if let Some(pair) = self.recording_handles.get(&device) {
+        if let Some(pair) = self.recording_handles.get(device) {
             let handle = pair.value();
 
             handle.lock().await.abort();
         }
 
-        self.recording_handles.remove(&device);
+        self.recording_handles.remove(device);
 
         Ok(())
     }
@@ -288,6 +343,14 @@ impl AudioManager {
     }
 
     pub async fn start_device(&self, device: &AudioDevice) -> Result<()> {
+        if self.channel_split_devices().await.contains(&device.to_string()) {
+            return self.start_channel_split_device(device).await;
+        }
+
+        self.start_single_device(device).await
+    }
+
+    async fn start_single_device(&self, device: &AudioDevice) -> Result<()> {
         if let Err(e) = self.device_manager.start_device(device).await {
             let err_str = e.to_string();
 
@@ -318,6 +381,46 @@ impl AudioManager {
         Ok(())
     }
 
+    /// Starts a device configured for channel-split recording: instead of one
+    /// downmixed-to-mono stream, starts an independent left/right virtual
+    /// device pair (see `AudioDevice::channel_virtual`), each going through
+    /// the exact same recording/transcription pipeline as any other device.
+    async fn start_channel_split_device(&self, device: &AudioDevice) -> Result<()> {
+        let started = match self.device_manager.start_channel_split_device(device).await {
+            Ok(devices) => devices,
+            Err(e) => {
+                let err_str = e.to_string();
+                if err_str.contains("Failed to build input stream") {
+                    return Err(anyhow!("Device {device} not found"));
+                } else if !err_str.contains("already running") {
+                    return Err(e);
+                }
+                Vec::new()
+            }
+        };
+
+        for virtual_device in &started {
+            if !self.recording_handles.contains_key(virtual_device) {
+                if let Some(is_running) = self.device_manager.is_running_mut(virtual_device) {
+                    is_running.store(true, Ordering::Relaxed);
+                }
+                let handle = self.record_device(virtual_device).await?;
+                self.recording_handles
+                    .insert(virtual_device.clone(), Arc::new(Mutex::new(handle)));
+            }
+
+            if !self.enabled_devices().await.contains(&virtual_device.to_string()) {
+                self.options
+                    .write()
+                    .await
+                    .enabled_devices
+                    .insert(virtual_device.to_string());
+            }
+        }
+
+        Ok(())
+    }
+
     pub async fn use_all_devices(&self) -> bool {
         self.options.read().await.use_all_devices
     }
@@ -338,6 +441,7 @@ impl AudioManager {
         let realtime_enabled = options.enable_realtime;
         let device_clone = device.clone();
         let metrics = self.metrics.clone();
+        let spill_output_path = options.output_path.clone();
 
         let recording_handle = tokio::spawn(async move {
             let record_and_transcribe_handle = tokio::spawn(record_and_transcribe(
@@ -346,6 +450,7 @@ impl AudioManager {
                 recording_sender.clone(),
                 is_running.clone(),
                 metrics,
+                spill_output_path,
             ));
 
             let realtime_handle = if realtime_enabled {
@@ -406,6 +511,7 @@ impl AudioManager {
         let deepgram_api_key = options.deepgram_api_key.clone();
         let audio_transcription_engine = options.transcription_engine.clone();
         let vocabulary = options.vocabulary.clone();
+        let denoise_devices = options.denoise_devices.clone();
         let is_batch_mode = options.transcription_mode == TranscriptionMode::Batch;
         let vad_engine = self.vad_engine.clone();
         let whisper_receiver = self.recording_receiver.clone();
@@ -489,9 +595,47 @@ impl AudioManager {
         };
 
         Ok(tokio::spawn(async move {
+            // Tracks whether a `capture_gaps` row is currently open for a
+            // given device's user-initiated pause, so we don't insert a new
+            // row on every chunk while the pause is active.
+            let mut pause_gap_open: std::collections::HashMap<String, bool> =
+                std::collections::HashMap::new();
+
             while let Ok(audio) = whisper_receiver.recv() {
                 debug!("received audio from device: {:?}", audio.device.name);
 
+                // Skip this direction's audio entirely while the user has
+                // paused it (tray/API privacy pause), recording an explicit
+                // capture_gaps row so the timeline can show why audio is
+                // missing for this device during the pause.
+                let device_name = audio.device.to_string();
+                let denoise_enabled = denoise_devices.contains(&device_name);
+                let pipeline = match audio.device.device_type {
+                    DeviceType::Input => CapturePipeline::AudioInput,
+                    DeviceType::Output => CapturePipeline::AudioOutput,
+                };
+                if privacy_pause::is_paused(pipeline) {
+                    if !*pause_gap_open.entry(device_name.clone()).or_insert(false) {
+                        if let Err(e) = db
+                            .open_capture_gap(
+                                &device_name,
+                                privacy_pause::PAUSE_GAP_REASON,
+                                chrono::Utc::now(),
+                            )
+                            .await
+                        {
+                            warn!("failed to open capture gap for {}: {}", device_name, e);
+                        }
+                        pause_gap_open.insert(device_name.clone(), true);
+                    }
+                    continue;
+                } else if pause_gap_open.get(&device_name).copied().unwrap_or(false) {
+                    if let Err(e) = db.close_open_capture_gap(&device_name, chrono::Utc::now()).await {
+                        warn!("failed to close capture gap for {}: {}", device_name, e);
+                    }
+                    pause_gap_open.insert(device_name.clone(), false);
+                }
+
                 // Audio-based call detection: update meeting detector with speech activity
                 if let Some(ref meeting) = meeting_detector {
                     let rms = {
@@ -505,7 +649,12 @@ impl AudioManager {
                 // This ensures audio survives app restarts and can be retranscribed later.
                 let persisted_file_path = if let Some(ref out) = output_path {
                     let resampled = if audio.sample_rate != SAMPLE_RATE {
-                        match resample(audio.data.as_ref(), audio.sample_rate, SAMPLE_RATE) {
+                        match resample(
+                            &audio.device.to_string(),
+                            audio.data.as_ref(),
+                            audio.sample_rate,
+                            SAMPLE_RATE,
+                        ) {
                             Ok(r) => r,
                             Err(e) => {
                                 error!("failed to resample for early persist: {:?}", e);
@@ -556,6 +705,7 @@ impl AudioManager {
                                 deepgram_api_key.clone(),
                                 languages.clone(),
                                 &vocabulary,
+                                metrics.clone(),
                             )
                             .await;
                             info!("batch mode: transcribed {} chunks after session end", count);
@@ -579,6 +729,7 @@ impl AudioManager {
                                 &vocabulary,
                                 persisted_file_path.clone(),
                                 alternate_stt.clone(),
+                                denoise_enabled,
                             )
                             .await
                             {
@@ -603,6 +754,7 @@ impl AudioManager {
                             &vocabulary,
                             persisted_file_path.clone(),
                             alternate_stt.clone(),
+                            denoise_enabled,
                         )
                         .await
                         {
@@ -627,6 +779,7 @@ impl AudioManager {
                         &vocabulary,
                         persisted_file_path.clone(),
                         alternate_stt.clone(),
+                        denoise_enabled,
                     )
                     .await
                     {
@@ -643,6 +796,7 @@ impl AudioManager {
         let options = self.options.read().await;
         let transcription_engine = options.transcription_engine.clone();
         let use_pii_removal = options.use_pii_removal;
+        let transcript_filter = options.transcript_filter;
         drop(options); // Release lock before spawning
         let metrics = self.metrics.clone();
         let on_insert = self.on_transcription_insert.clone();
@@ -651,6 +805,7 @@ impl AudioManager {
             transcription_receiver,
             transcription_engine,
             use_pii_removal,
+            transcript_filter,
             metrics,
             on_insert,
         )))
@@ -688,6 +843,12 @@ impl AudioManager {
         self.options.read().await.enabled_devices.clone()
     }
 
+    /// Devices (by name) configured to record as two per-channel virtual
+    /// devices instead of one downmixed-to-mono stream.
+    pub async fn channel_split_devices(&self) -> HashSet<String> {
+        self.options.read().await.channel_split_devices.clone()
+    }
+
     /// Returns a reference to the meeting detector, if batch mode is active.
     pub fn meeting_detector(&self) -> Option<&Arc<MeetingDetector>> {
         self.meeting_detector.as_ref()
@@ -723,6 +884,40 @@ impl AudioManager {
         self.options.read().await.vocabulary.clone()
     }
 
+    /// Transcribes a single deferred (untranscribed) chunk immediately —
+    /// the "transcribe now" priority lane for a chunk the user clicked on in
+    /// the timeline, instead of waiting for the next batch reconciliation
+    /// sweep. Errors if the Whisper model isn't loaded yet, the chunk
+    /// doesn't exist, or it's already been transcribed.
+    pub async fn transcribe_now(&self, chunk_id: i64) -> anyhow::Result<String> {
+        let whisper_context = self
+            .whisper_context()
+            .await
+            .ok_or_else(|| anyhow::anyhow!("whisper model not loaded yet"))?;
+        let engine = self.transcription_engine().await;
+        let deepgram_api_key = self.deepgram_api_key().await;
+        let languages = self.languages().await;
+        let vocabulary = self.vocabulary().await;
+
+        super::reconciliation::transcribe_chunk_now(
+            &self.db,
+            chunk_id,
+            &whisper_context,
+            engine,
+            deepgram_api_key,
+            languages,
+            &vocabulary,
+        )
+        .await
+    }
+
+    /// Replace the custom vocabulary used to bias/correct future transcriptions.
+    /// Takes effect on the next chunk transcribed — in-flight transcriptions
+    /// already hold a copy of the old list.
+    pub async fn set_vocabulary(&self, vocabulary: Vec<crate::transcription::VocabularyEntry>) {
+        self.options.write().await.vocabulary = vocabulary;
+    }
+
     /// Check for recording handles that have finished (crashed or timed out)
     /// Returns device names that need to be cleaned up and restarted
     pub async fn check_stale_recording_handles(&self) -> Vec<String> {
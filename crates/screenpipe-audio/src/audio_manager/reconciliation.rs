@@ -11,6 +11,7 @@ use tracing::{debug, error, warn};
 use whisper_rs::WhisperContext;
 
 use crate::core::engine::AudioTranscriptionEngine;
+use crate::metrics::AudioPipelineMetrics;
 use crate::transcription::stt::stt;
 use crate::transcription::VocabularyEntry;
 use crate::utils::ffmpeg::read_audio_from_file;
@@ -25,6 +26,21 @@ const MAX_BATCH_CHUNKS: usize = 10;
 /// This gives Whisper 2-5 minutes of context instead of 30s, significantly
 /// improving transcription quality — the whole point of batch mode.
 ///
+/// Progress is reported two ways as the sweep runs: `metrics` (read by
+/// `GET /audio/metrics` and `/ws/metrics`, zero extra DB queries) and a
+/// `reconciliation_progress` event on the event bus (see `/ws/events`), so a
+/// long post-meeting sweep isn't silent for minutes at a time.
+///
+/// Resumable by construction, not by a separate checkpoint: each chunk is
+/// only deleted (secondary chunks) or rewritten in place (the batch's primary
+/// chunk, via [`DatabaseManager::replace_audio_transcription`]) *after* it's
+/// successfully transcribed, and the next sweep re-queries
+/// [`DatabaseManager::get_untranscribed_chunks`] fresh. So if the app
+/// restarts mid-sweep, whatever chunks didn't finish are still orphaned and
+/// get picked up — and counted from a fresh total — by the next sweep;
+/// `metrics`'s in-memory total/done just reset with it, same as any other
+/// per-run counter here.
+///
 /// Returns the number of chunks successfully transcribed.
 pub async fn reconcile_untranscribed(
     db: &DatabaseManager,
@@ -33,6 +49,7 @@ pub async fn reconcile_untranscribed(
     deepgram_api_key: Option<String>,
     languages: Vec<Language>,
     vocabulary: &[VocabularyEntry],
+    metrics: Arc<AudioPipelineMetrics>,
 ) -> usize {
     let since = chrono::Utc::now() - chrono::Duration::hours(24);
     let chunks = match db.get_untranscribed_chunks(since, 50).await {
@@ -51,6 +68,11 @@ pub async fn reconcile_untranscribed(
         "reconciliation: found {} untranscribed audio chunks",
         chunks.len()
     );
+    metrics.start_reconciliation_run(chunks.len() as u64);
+    let _ = screenpipe_events::send_event(
+        "reconciliation_progress",
+        serde_json::json!({"total_chunks": chunks.len(), "done_chunks": 0}),
+    );
 
     // Group consecutive chunks by device for batched transcription.
     let batches = group_chunks_by_device(&chunks);
@@ -131,7 +153,7 @@ pub async fn reconcile_untranscribed(
         };
 
         // Transcribe the concatenated audio in one shot
-        let full_text = match stt(
+        let (full_text, confidence) = match stt(
             &combined_samples,
             sample_rate,
             &device_name,
@@ -165,6 +187,7 @@ pub async fn reconcile_untranscribed(
                 is_input,
                 primary_chunk.timestamp,
                 Some(batch_duration),
+                confidence.map(|c| c as f64),
             )
             .await
         {
@@ -175,6 +198,7 @@ pub async fn reconcile_untranscribed(
             continue;
         }
         success_count += 1;
+        metrics.record_reconciliation_chunk_done();
 
         // Delete the secondary chunks — they're now merged into the primary.
         for chunk in &valid_chunks[1..] {
@@ -187,15 +211,103 @@ pub async fn reconcile_untranscribed(
             // Clean up the audio file too
             let _ = std::fs::remove_file(&chunk.file_path);
             success_count += 1;
+            metrics.record_reconciliation_chunk_done();
         }
 
+        let _ = screenpipe_events::send_event(
+            "reconciliation_progress",
+            serde_json::json!({
+                "total_chunks": chunks.len(),
+                "done_chunks": success_count,
+            }),
+        );
+
         // Yield to other tasks between batches
         tokio::task::yield_now().await;
     }
 
+    metrics.finish_reconciliation_run();
     success_count
 }
 
+/// Transcribes a single untranscribed chunk immediately, bypassing the batch
+/// queue and the 300s sweep interval — the "transcribe now" priority lane.
+///
+/// This does not reorder or preempt anything in `reconcile_untranscribed`;
+/// there is no in-memory work queue to reorder. Batch mode's "queue" is just
+/// whatever [`DatabaseManager::get_untranscribed_chunks`] returns on the next
+/// sweep. Priority here means: fetch this one chunk now, transcribe it on its
+/// own fresh [`WhisperState`] (safe to run concurrently with an in-flight
+/// sweep — `reconcile_untranscribed` already creates one state per batch),
+/// and write the result — without waiting for the sweep or its batching.
+///
+/// Returns the transcribed text, or an error if the chunk doesn't exist,
+/// already has a transcription, or its audio file is missing.
+#[allow(clippy::too_many_arguments)]
+pub async fn transcribe_chunk_now(
+    db: &DatabaseManager,
+    chunk_id: i64,
+    whisper_context: &WhisperContext,
+    engine: Arc<AudioTranscriptionEngine>,
+    deepgram_api_key: Option<String>,
+    languages: Vec<Language>,
+    vocabulary: &[VocabularyEntry],
+) -> anyhow::Result<String> {
+    let chunk = db
+        .get_untranscribed_chunk_by_id(chunk_id)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("chunk {} not found or already transcribed", chunk_id))?;
+
+    let path = Path::new(&chunk.file_path);
+    if !path.exists() {
+        return Err(anyhow::anyhow!(
+            "audio file missing for chunk {}: {}",
+            chunk_id,
+            chunk.file_path
+        ));
+    }
+
+    let (device_name, is_input) = extract_device_from_path(&chunk.file_path);
+
+    let path_owned = chunk.file_path.clone();
+    let (samples, sample_rate) =
+        tokio::task::spawn_blocking(move || read_audio_from_file(Path::new(&path_owned)))
+            .await
+            .map_err(|e| anyhow::anyhow!("spawn_blocking panicked for chunk {}: {}", chunk_id, e))??;
+
+    let mut state = whisper_context
+        .create_state()
+        .map_err(|e| anyhow::anyhow!("failed to create whisper state: {}", e))?;
+
+    let (text, confidence) = stt(
+        &samples,
+        sample_rate,
+        &device_name,
+        engine.clone(),
+        deepgram_api_key,
+        languages,
+        &mut state,
+        vocabulary,
+        None,
+    )
+    .await?;
+
+    let duration_secs = samples.len() as f64 / sample_rate as f64;
+    db.replace_audio_transcription(
+        chunk.id,
+        &text,
+        &engine.to_string(),
+        &device_name,
+        is_input,
+        chunk.timestamp,
+        Some(duration_secs),
+        confidence.map(|c| c as f64),
+    )
+    .await?;
+
+    Ok(text)
+}
+
 /// Group chunks into batches of consecutive chunks from the same device.
 /// Chunks are already ordered by timestamp ASC from the DB query.
 fn group_chunks_by_device(chunks: &[UntranscribedChunk]) -> Vec<Vec<&UntranscribedChunk>> {
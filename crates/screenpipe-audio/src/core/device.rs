@@ -16,7 +16,7 @@ pub struct DeviceControl {
     pub is_paused: bool,
 }
 
-#[derive(OaSchema, Clone, Eq, PartialEq, Hash, Serialize, Debug, Deserialize)]
+#[derive(OaSchema, Clone, Copy, Eq, PartialEq, Hash, Serialize, Debug, Deserialize)]
 pub enum DeviceType {
     Input,
     Output,
@@ -49,6 +49,32 @@ impl From<DeviceType> for Arc<screenpipe_db::DeviceType> {
     }
 }
 
+/// Which channel of a multi-channel capture a channel-split virtual device
+/// reads, instead of the usual downmix-to-mono. See
+/// `AudioManagerBuilder::channel_split_devices` and
+/// `AudioDevice::channel_virtual`.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Serialize, Debug, Deserialize)]
+pub enum ChannelSelector {
+    Left,
+    Right,
+}
+
+impl ChannelSelector {
+    pub fn index(self) -> u16 {
+        match self {
+            ChannelSelector::Left => 0,
+            ChannelSelector::Right => 1,
+        }
+    }
+
+    fn suffix(self) -> &'static str {
+        match self {
+            ChannelSelector::Left => "L",
+            ChannelSelector::Right => "R",
+        }
+    }
+}
+
 #[derive(Clone, Eq, PartialEq, Hash, Serialize, Debug)]
 pub struct AudioDevice {
     pub name: String,
@@ -92,6 +118,19 @@ impl AudioDevice {
 
         Ok(AudioDevice::new(name, device_type))
     }
+
+    /// Synthesized virtual device for one channel of this device's capture,
+    /// used by channel-split recording. Not a real, separately enumerable
+    /// device — see `DeviceManager::start_channel_split_device`.
+    pub fn channel_virtual(&self, channel: ChannelSelector) -> AudioDevice {
+        AudioDevice::new(format!("{} [{}]", self.name, channel.suffix()), self.device_type)
+    }
+
+    /// Whether this device is a synthesized per-channel virtual device
+    /// created by `channel_virtual` (as opposed to a real, enumerable one).
+    pub fn is_channel_virtual(&self) -> bool {
+        self.name.ends_with(" [L]") || self.name.ends_with(" [R]")
+    }
 }
 
 impl fmt::Display for AudioDevice {
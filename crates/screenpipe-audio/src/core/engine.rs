@@ -36,6 +36,45 @@ impl std::str::FromStr for AudioTranscriptionEngine {
     }
 }
 
+impl AudioTranscriptionEngine {
+    /// Relative transcription quality, worst to best. Declaration order above
+    /// isn't a quality ordering (`Deepgram` is declared first despite being
+    /// high-quality), so callers deciding whether to re-transcribe a chunk
+    /// with a "better" engine should compare this instead of enum position.
+    /// `Disabled` ranks lowest since it produces no transcript at all.
+    pub fn quality_rank(&self) -> u8 {
+        match self {
+            AudioTranscriptionEngine::Disabled => 0,
+            AudioTranscriptionEngine::WhisperTinyQuantized => 1,
+            AudioTranscriptionEngine::WhisperTiny => 2,
+            AudioTranscriptionEngine::WhisperLargeV3TurboQuantized => 3,
+            AudioTranscriptionEngine::WhisperLargeV3Quantized => 4,
+            AudioTranscriptionEngine::WhisperLargeV3Turbo => 5,
+            AudioTranscriptionEngine::WhisperLargeV3 => 6,
+            AudioTranscriptionEngine::Qwen3Asr => 7,
+            AudioTranscriptionEngine::Deepgram => 8,
+        }
+    }
+
+    /// Parses the engine name as stored in `audio_transcriptions.transcription_engine`
+    /// (this type's [`Display`](fmt::Display) output, e.g. `"WhisperTiny"`) rather
+    /// than the CLI/config strings [`FromStr`](std::str::FromStr) accepts.
+    pub fn from_stored_name(s: &str) -> Option<Self> {
+        match s {
+            "Deepgram" => Some(Self::Deepgram),
+            "WhisperTiny" => Some(Self::WhisperTiny),
+            "WhisperTinyQuantized" => Some(Self::WhisperTinyQuantized),
+            "WhisperLargeV3Turbo" => Some(Self::WhisperLargeV3Turbo),
+            "WhisperLargeV3TurboQuantized" => Some(Self::WhisperLargeV3TurboQuantized),
+            "WhisperLargeV3" => Some(Self::WhisperLargeV3),
+            "WhisperLargeV3Quantized" => Some(Self::WhisperLargeV3Quantized),
+            "Qwen3Asr" => Some(Self::Qwen3Asr),
+            "Disabled" => Some(Self::Disabled),
+            _ => None,
+        }
+    }
+}
+
 impl fmt::Display for AudioTranscriptionEngine {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
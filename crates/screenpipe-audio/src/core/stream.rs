@@ -18,11 +18,36 @@ use tokio::task::LocalSet;
 use tracing::{error, warn};
 
 #[cfg(not(all(target_os = "linux", feature = "pulseaudio")))]
-use crate::utils::audio::audio_to_mono;
+use crate::utils::audio::{audio_to_mono, extract_channel};
 
 #[cfg(not(all(target_os = "linux", feature = "pulseaudio")))]
 use super::device::get_cpal_device_and_config;
 use super::device::AudioDevice;
+#[cfg(not(all(target_os = "linux", feature = "pulseaudio")))]
+use super::device::ChannelSelector;
+
+/// How a capture callback should collapse an interleaved multi-channel
+/// buffer into the mono `Vec<f32>` the rest of the pipeline expects.
+#[cfg(not(all(target_os = "linux", feature = "pulseaudio")))]
+#[derive(Clone, Copy)]
+enum MonoStrategy {
+    /// Average every channel together (the historical, default behavior).
+    Downmix,
+    /// Keep only one channel — used by channel-split virtual devices.
+    ExtractChannel(ChannelSelector),
+}
+
+#[cfg(not(all(target_os = "linux", feature = "pulseaudio")))]
+impl MonoStrategy {
+    fn apply(self, data: &[f32], channels: u16) -> Vec<f32> {
+        match self {
+            MonoStrategy::Downmix => audio_to_mono(data, channels),
+            MonoStrategy::ExtractChannel(selector) => {
+                extract_channel(data, channels, selector.index())
+            }
+        }
+    }
+}
 
 /// Backend-agnostic audio stream configuration.
 /// Replaces direct use of `cpal::SupportedStreamConfig` so that alternative
@@ -82,50 +107,123 @@ impl AudioStream {
     pub async fn from_device(
         device: Arc<AudioDevice>,
         is_running: Arc<AtomicBool>,
+    ) -> Result<Self> {
+        Self::from_device_and_config(device.clone(), device, is_running).await
+    }
+
+    /// Like `from_device`, but captures a single channel of `physical_device`
+    /// instead of downmixing every channel to mono, and identifies the
+    /// resulting stream as `virtual_device` throughout the rest of the
+    /// pipeline (recording, transcription, speaker attribution) — so a
+    /// stereo device can be split into two independent "devices".
+    ///
+    /// Not supported on the PulseAudio backend yet — see
+    /// `DeviceManager::start_channel_split_device` for the fallback.
+    #[cfg(not(all(target_os = "linux", feature = "pulseaudio")))]
+    pub async fn from_device_channel(
+        physical_device: Arc<AudioDevice>,
+        virtual_device: Arc<AudioDevice>,
+        is_running: Arc<AtomicBool>,
+        channel: ChannelSelector,
+    ) -> Result<Self> {
+        Self::from_device_impl(
+            physical_device,
+            virtual_device,
+            is_running,
+            MonoStrategy::ExtractChannel(channel),
+        )
+        .await
+    }
+
+    #[cfg(not(all(target_os = "linux", feature = "pulseaudio")))]
+    async fn from_device_and_config(
+        physical_device: Arc<AudioDevice>,
+        identity_device: Arc<AudioDevice>,
+        is_running: Arc<AtomicBool>,
+    ) -> Result<Self> {
+        Self::from_device_impl(
+            physical_device,
+            identity_device,
+            is_running,
+            MonoStrategy::Downmix,
+        )
+        .await
+    }
+
+    #[cfg(all(target_os = "linux", feature = "pulseaudio"))]
+    async fn from_device_and_config(
+        physical_device: Arc<AudioDevice>,
+        identity_device: Arc<AudioDevice>,
+        is_running: Arc<AtomicBool>,
     ) -> Result<Self> {
         let (tx, _) = broadcast::channel::<Vec<f32>>(1000);
         let tx_clone = tx.clone();
         let is_disconnected = Arc::new(AtomicBool::new(false));
         let (stream_control_tx, stream_control_rx) = mpsc::channel();
 
-        #[cfg(all(target_os = "linux", feature = "pulseaudio"))]
-        let (audio_config, stream_thread) = {
-            let config = super::pulse::get_pulse_device_config(&device)?;
-            let thread = super::pulse::spawn_pulse_capture_thread(
-                (*device).clone(),
-                config.clone(),
-                tx,
-                is_running.clone(),
-                is_disconnected.clone(),
-            )?;
-            // Drop the unused receiver so stop() doesn't block on it
-            drop(stream_control_rx);
-            (config, thread)
-        };
+        let config = super::pulse::get_pulse_device_config(&physical_device)?;
+        let stream_thread = super::pulse::spawn_pulse_capture_thread(
+            (*physical_device).clone(),
+            config.clone(),
+            tx,
+            is_running.clone(),
+            is_disconnected.clone(),
+        )?;
+        // Drop the unused receiver so stop() doesn't block on it
+        drop(stream_control_rx);
+
+        super::device_quality::observe_stream_sample_rate(
+            &identity_device.to_string(),
+            config.sample_rate().0,
+        );
 
-        #[cfg(not(all(target_os = "linux", feature = "pulseaudio")))]
-        let (audio_config, stream_thread) = {
-            let (cpal_audio_device, config) = get_cpal_device_and_config(&device).await?;
-            let audio_config = AudioStreamConfig::from(&config);
-            let channels = config.channels();
-            let is_running_weak = Arc::downgrade(&is_running);
-
-            let thread = Self::spawn_audio_thread(
-                cpal_audio_device,
-                config,
-                tx,
-                stream_control_rx,
-                channels,
-                is_running_weak,
-                is_disconnected.clone(),
-                stream_control_tx.clone(),
-            )
-            .await?;
-            (audio_config, thread)
-        };
+        Ok(AudioStream {
+            device: identity_device,
+            device_config: config,
+            transmitter: Arc::new(tx_clone),
+            stream_control: stream_control_tx,
+            stream_thread: Some(Arc::new(tokio::sync::Mutex::new(Some(stream_thread)))),
+            is_disconnected,
+        })
+    }
+
+    #[cfg(not(all(target_os = "linux", feature = "pulseaudio")))]
+    async fn from_device_impl(
+        physical_device: Arc<AudioDevice>,
+        identity_device: Arc<AudioDevice>,
+        is_running: Arc<AtomicBool>,
+        mono_strategy: MonoStrategy,
+    ) -> Result<Self> {
+        let (tx, _) = broadcast::channel::<Vec<f32>>(1000);
+        let tx_clone = tx.clone();
+        let is_disconnected = Arc::new(AtomicBool::new(false));
+        let (stream_control_tx, stream_control_rx) = mpsc::channel();
+
+        let (cpal_audio_device, config) = get_cpal_device_and_config(&physical_device).await?;
+        let audio_config = AudioStreamConfig::from(&config);
+        let channels = config.channels();
+        let is_running_weak = Arc::downgrade(&is_running);
+
+        let stream_thread = Self::spawn_audio_thread(
+            cpal_audio_device,
+            config,
+            tx,
+            stream_control_rx,
+            channels,
+            mono_strategy,
+            is_running_weak,
+            is_disconnected.clone(),
+            stream_control_tx.clone(),
+        )
+        .await?;
+
+        super::device_quality::observe_stream_sample_rate(
+            &identity_device.to_string(),
+            audio_config.sample_rate().0,
+        );
 
         Ok(AudioStream {
-            device,
+            device: identity_device,
             device_config: audio_config,
             transmitter: Arc::new(tx_clone),
             stream_control: stream_control_tx,
@@ -142,6 +240,7 @@ impl AudioStream {
         tx: broadcast::Sender<Vec<f32>>,
         stream_control_rx: mpsc::Receiver<StreamControl>,
         channels: u16,
+        mono_strategy: MonoStrategy,
         is_running_weak: std::sync::Weak<AtomicBool>,
         is_disconnected: Arc<AtomicBool>,
         stream_control_tx: mpsc::Sender<StreamControl>,
@@ -156,7 +255,7 @@ impl AudioStream {
                 stream_control_tx,
             );
 
-            let stream = build_input_stream(&device, &config, channels, tx, error_callback);
+            let stream = build_input_stream(&device, &config, channels, mono_strategy, tx, error_callback);
 
             match stream {
                 Ok(stream) => {
@@ -251,6 +350,7 @@ fn build_input_stream(
     device: &cpal::Device,
     config: &cpal::SupportedStreamConfig,
     channels: u16,
+    mono_strategy: MonoStrategy,
     tx: broadcast::Sender<Vec<f32>>,
     error_callback: impl FnMut(StreamError) + Send + 'static,
 ) -> Result<cpal::Stream> {
@@ -259,7 +359,7 @@ fn build_input_stream(
             .build_input_stream(
                 &config.config(),
                 move |data: &[f32], _: &_| {
-                    let mono = audio_to_mono(data, channels);
+                    let mono = mono_strategy.apply(data, channels);
                     let _ = tx.send(mono);
                 },
                 error_callback,
@@ -271,7 +371,7 @@ fn build_input_stream(
                 &config.config(),
                 move |data: &[i16], _: &_| {
                     let f32_data: Vec<f32> = data.iter().map(|&s| s as f32 / 32768.0).collect();
-                    let mono = audio_to_mono(&f32_data, channels);
+                    let mono = mono_strategy.apply(&f32_data, channels);
                     let _ = tx.send(mono);
                 },
                 error_callback,
@@ -284,7 +384,7 @@ fn build_input_stream(
                 move |data: &[i32], _: &_| {
                     let f32_data: Vec<f32> =
                         data.iter().map(|&s| (s as f64 / 2147483648.0) as f32).collect();
-                    let mono = audio_to_mono(&f32_data, channels);
+                    let mono = mono_strategy.apply(&f32_data, channels);
                     let _ = tx.send(mono);
                 },
                 error_callback,
@@ -296,7 +396,7 @@ fn build_input_stream(
                 &config.config(),
                 move |data: &[i8], _: &_| {
                     let f32_data: Vec<f32> = data.iter().map(|&s| s as f32 / 128.0).collect();
-                    let mono = audio_to_mono(&f32_data, channels);
+                    let mono = mono_strategy.apply(&f32_data, channels);
                     let _ = tx.send(mono);
                 },
                 error_callback,
@@ -28,6 +28,15 @@ const AUDIO_MEETING_COOLDOWN: Duration = Duration::from_secs(120);
 /// from Google Meet but is still on the call), not a standalone detector.
 const APP_CONFIRMATION_WINDOW: Duration = Duration::from_secs(300); // 5 minutes
 
+/// How far ahead of its scheduled start a calendar event with a known
+/// conferencing link (`CalendarSignal::meeting_url`) is treated as "active"
+/// for calendar-based detection. Lets a meeting be pre-armed while the user
+/// is still in a browser tab whose app name doesn't say "Zoom" or "Meet" yet.
+/// Only applies when `meeting_url` is set — plain events without a
+/// conferencing link still require `start_epoch_ms <= now` to avoid false
+/// positives on non-video meetings.
+const MEETING_PRE_ARM_WINDOW: Duration = Duration::from_secs(300); // 5 minutes
+
 /// A calendar event signal fed into the meeting detector.
 /// Contains only the fields needed for meeting detection — no serde/chrono deps.
 #[derive(Debug, Clone)]
@@ -37,6 +46,9 @@ pub struct CalendarSignal {
     pub start_epoch_ms: i64,
     pub end_epoch_ms: i64,
     pub attendees: Vec<String>,
+    /// Conferencing URL (Zoom/Google Meet/Teams) extracted from the event's
+    /// location or description, if any. Gates the pre-arm window below.
+    pub meeting_url: Option<String>,
 }
 
 /// Calendar context for the currently active calendar-based meeting.
@@ -62,6 +74,9 @@ pub struct CalendarContext {
 /// the current time AND there is audio activity on at least one channel
 /// (input OR output), the meeting is active. This is more lenient than
 /// audio-only extension because the calendar provides strong intent signal.
+/// Events with a known conferencing link are pre-armed [`MEETING_PRE_ARM_WINDOW`]
+/// before their scheduled start, so audio confirmation can latch the meeting
+/// even before the user's app/tab name looks like a call.
 pub struct MeetingDetector {
     /// Lowercase app names that are always considered meetings
     meeting_apps: HashSet<String>,
@@ -314,11 +329,19 @@ impl MeetingDetector {
     }
 
     /// Returns the first active calendar event (2+ attendees, overlapping now).
+    ///
+    /// Events with a `meeting_url` are considered "started" up to
+    /// [`MEETING_PRE_ARM_WINDOW`] early, so a known Zoom/Meet/Teams link can
+    /// pre-arm detection ahead of its scheduled start.
     fn active_calendar_event<'a>(&self, state: &'a MeetingState, now: i64) -> Option<&'a CalendarSignal> {
+        let pre_arm_ms = MEETING_PRE_ARM_WINDOW.as_millis() as i64;
         state.calendar_events.iter().find(|e| {
-            e.attendees.len() >= 2
-                && e.start_epoch_ms <= now
-                && e.end_epoch_ms > now
+            let effective_start = if e.meeting_url.is_some() {
+                e.start_epoch_ms - pre_arm_ms
+            } else {
+                e.start_epoch_ms
+            };
+            e.attendees.len() >= 2 && effective_start <= now && e.end_epoch_ms > now
         })
     }
 
@@ -1044,6 +1067,7 @@ mod tests {
             start_epoch_ms: now - 60_000,  // started 1 min ago
             end_epoch_ms: now + 3_600_000, // ends in 1 hour
             attendees: attendees.iter().map(|s| s.to_string()).collect(),
+            meeting_url: None,
         }
     }
 
@@ -1056,6 +1080,7 @@ mod tests {
             start_epoch_ms: now + 3_600_000,  // starts in 1 hour
             end_epoch_ms: now + 7_200_000,     // ends in 2 hours
             attendees: attendees.iter().map(|s| s.to_string()).collect(),
+            meeting_url: None,
         }
     }
 
@@ -1068,6 +1093,21 @@ mod tests {
             start_epoch_ms: now - 7_200_000,  // started 2 hours ago
             end_epoch_ms: now - 3_600_000,     // ended 1 hour ago
             attendees: attendees.iter().map(|s| s.to_string()).collect(),
+            meeting_url: None,
+        }
+    }
+
+    /// Helper: create a CalendarSignal starting a few minutes from now with a
+    /// known conferencing link, for pre-arm window tests.
+    fn calendar_soon_with_url(title: &str, attendees: &[&str], starts_in_ms: i64) -> CalendarSignal {
+        let now = now_millis();
+        CalendarSignal {
+            event_id: "soon-event".to_string(),
+            title: title.to_string(),
+            start_epoch_ms: now + starts_in_ms,
+            end_epoch_ms: now + starts_in_ms + 3_600_000,
+            attendees: attendees.iter().map(|s| s.to_string()).collect(),
+            meeting_url: Some("https://meet.google.com/abc-defg-hij".to_string()),
         }
     }
 
@@ -1250,4 +1290,60 @@ mod tests {
             "calendar meeting should end when the calendar event ends"
         );
     }
+
+    #[tokio::test]
+    async fn test_calendar_event_with_meeting_url_pre_arms_within_window() {
+        let detector = MeetingDetector::new();
+
+        // Starts in 3 minutes, well inside MEETING_PRE_ARM_WINDOW (5 minutes),
+        // and has a known conferencing link.
+        let events = vec![calendar_soon_with_url(
+            "Design Review",
+            &["Alice", "Bob"],
+            180_000,
+        )];
+        detector.on_calendar_events(events).await;
+        detector.on_audio_activity(&DeviceType::Output, true);
+
+        assert!(
+            detector.is_in_meeting(),
+            "event with meeting_url should pre-arm before its scheduled start"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_calendar_event_without_meeting_url_does_not_pre_arm() {
+        let detector = MeetingDetector::new();
+
+        // Same lead time as above, but no conferencing link — should NOT pre-arm.
+        let mut event = calendar_soon_with_url("Design Review", &["Alice", "Bob"], 180_000);
+        event.meeting_url = None;
+        let events = vec![event];
+        detector.on_calendar_events(events).await;
+        detector.on_audio_activity(&DeviceType::Output, true);
+
+        assert!(
+            !detector.is_in_meeting(),
+            "event without meeting_url should not pre-arm early"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_calendar_event_with_meeting_url_outside_pre_arm_window() {
+        let detector = MeetingDetector::new();
+
+        // Starts in 1 hour — outside MEETING_PRE_ARM_WINDOW even with a link.
+        let events = vec![calendar_soon_with_url(
+            "Design Review",
+            &["Alice", "Bob"],
+            3_600_000,
+        )];
+        detector.on_calendar_events(events).await;
+        detector.on_audio_activity(&DeviceType::Output, true);
+
+        assert!(
+            !detector.is_in_meeting(),
+            "event with meeting_url should still not pre-arm outside the window"
+        );
+    }
 }
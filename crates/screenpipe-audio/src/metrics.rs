@@ -53,6 +53,28 @@ pub struct AudioPipelineMetrics {
     /// Number of times transcription was resumed (transition to idle)
     pub batch_resume_events: AtomicU64,
 
+    // --- Deferred-chunk reconciliation progress ---
+    /// Chunks the current (or most recently finished) reconciliation sweep set out to process.
+    pub reconciliation_chunks_total: AtomicU64,
+    /// Chunks the current (or most recently finished) sweep has finished (transcribed or merged).
+    pub reconciliation_chunks_done: AtomicU64,
+    /// Unix timestamp (secs) the current sweep started at; 0 when no sweep is running.
+    pub reconciliation_started_at_ts: AtomicU64,
+
+    // --- Noise suppression (denoise) ---
+    /// Chunks that went through the denoise stage — CPU-budget accounting.
+    pub denoise_chunks_total: AtomicU64,
+    /// Cumulative CPU time spent in the denoise stage, microseconds.
+    pub denoise_cpu_micros_total: AtomicU64,
+    /// Cumulative transcription confidence × 1000 for chunks that WERE denoised.
+    pub denoise_confidence_sum_x1000: AtomicU64,
+    pub denoise_confidence_count: AtomicU64,
+    /// Cumulative transcription confidence × 1000 for chunks that were NOT denoised —
+    /// the A/B baseline (confidence is used as a WER proxy; there's no ground-truth
+    /// transcript to compute real WER against in production).
+    pub raw_confidence_sum_x1000: AtomicU64,
+    pub raw_confidence_count: AtomicU64,
+
     // --- Real-time audio level ---
     /// RMS amplitude × 10000, updated every audio buffer (~50-100ms). 0-10000 range.
     pub audio_level_rms_x10000: AtomicU64,
@@ -61,8 +83,21 @@ pub struct AudioPipelineMetrics {
     pub started_at: Instant,
     /// Unix timestamp (secs) of most recent DB insert — used by health check to avoid DB queries
     pub last_db_write_ts: AtomicU64,
+
+    // --- End-to-end pipeline latency (capture -> DB insert) ---
+    /// Per-bucket counts, indexed against `PIPELINE_LATENCY_BUCKET_BOUNDS_MS` (last slot
+    /// is the overflow bucket for anything above the highest bound).
+    pipeline_latency_buckets: [AtomicU64; PIPELINE_LATENCY_BUCKET_BOUNDS_MS.len() + 1],
+    pipeline_latency_count: AtomicU64,
+    pipeline_latency_sum_ms: AtomicU64,
 }
 
+/// Upper bounds (ms) for the pipeline latency histogram, sized to resolve the
+/// "transcripts show up 5 minutes late" class of bug report.
+const PIPELINE_LATENCY_BUCKET_BOUNDS_MS: [u64; 11] = [
+    100, 250, 500, 1_000, 2_500, 5_000, 10_000, 30_000, 60_000, 120_000, 300_000,
+];
+
 impl AudioPipelineMetrics {
     pub fn new() -> Self {
         Self {
@@ -83,9 +118,21 @@ impl AudioPipelineMetrics {
             segments_batch_processed: AtomicU64::new(0),
             batch_pause_events: AtomicU64::new(0),
             batch_resume_events: AtomicU64::new(0),
+            reconciliation_chunks_total: AtomicU64::new(0),
+            reconciliation_chunks_done: AtomicU64::new(0),
+            reconciliation_started_at_ts: AtomicU64::new(0),
+            denoise_chunks_total: AtomicU64::new(0),
+            denoise_cpu_micros_total: AtomicU64::new(0),
+            denoise_confidence_sum_x1000: AtomicU64::new(0),
+            denoise_confidence_count: AtomicU64::new(0),
+            raw_confidence_sum_x1000: AtomicU64::new(0),
+            raw_confidence_count: AtomicU64::new(0),
             audio_level_rms_x10000: AtomicU64::new(0),
             started_at: Instant::now(),
             last_db_write_ts: AtomicU64::new(0),
+            pipeline_latency_buckets: std::array::from_fn(|_| AtomicU64::new(0)),
+            pipeline_latency_count: AtomicU64::new(0),
+            pipeline_latency_sum_ms: AtomicU64::new(0),
         }
     }
 
@@ -184,6 +231,94 @@ impl AudioPipelineMetrics {
         self.batch_resume_events.fetch_add(1, Ordering::Relaxed);
     }
 
+    // --- Deferred-chunk reconciliation progress ---
+
+    /// Mark the start of a reconciliation sweep over `total_chunks` orphaned chunks.
+    pub fn start_reconciliation_run(&self, total_chunks: u64) {
+        self.reconciliation_chunks_total
+            .store(total_chunks, Ordering::Relaxed);
+        self.reconciliation_chunks_done.store(0, Ordering::Relaxed);
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        self.reconciliation_started_at_ts.store(now, Ordering::Relaxed);
+    }
+
+    /// One more chunk finished (transcribed as the primary of its batch, or merged
+    /// into one). Called per-chunk so progress is granular, not per-batch.
+    pub fn record_reconciliation_chunk_done(&self) {
+        self.reconciliation_chunks_done.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Mark the sweep finished — clears `started_at` (so `reconciliation_running`
+    /// goes false) while leaving `total`/`done` as the last sweep's final tally.
+    pub fn finish_reconciliation_run(&self) {
+        self.reconciliation_started_at_ts.store(0, Ordering::Relaxed);
+    }
+
+    // --- Noise suppression (denoise) ---
+
+    /// Record CPU time spent denoising one chunk — for CPU-budget accounting.
+    pub fn record_denoise_cpu(&self, elapsed: std::time::Duration) {
+        self.denoise_chunks_total.fetch_add(1, Ordering::Relaxed);
+        self.denoise_cpu_micros_total
+            .fetch_add(elapsed.as_micros() as u64, Ordering::Relaxed);
+    }
+
+    /// Record a completed transcription's confidence, split by whether the
+    /// chunk went through denoising — the A/B signal (confidence as WER proxy).
+    pub fn record_ab_confidence(&self, denoised: bool, confidence: f32) {
+        let confidence_x1000 = (confidence.clamp(0.0, 1.0) * 1000.0) as u64;
+        if denoised {
+            self.denoise_confidence_sum_x1000
+                .fetch_add(confidence_x1000, Ordering::Relaxed);
+            self.denoise_confidence_count.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.raw_confidence_sum_x1000
+                .fetch_add(confidence_x1000, Ordering::Relaxed);
+            self.raw_confidence_count.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    // --- End-to-end pipeline latency ---
+
+    /// Record the end-to-end latency (capture -> DB insert) of one audio chunk, in ms.
+    pub fn record_pipeline_latency_ms(&self, latency_ms: u64) {
+        self.pipeline_latency_count.fetch_add(1, Ordering::Relaxed);
+        self.pipeline_latency_sum_ms
+            .fetch_add(latency_ms, Ordering::Relaxed);
+        let bucket = PIPELINE_LATENCY_BUCKET_BOUNDS_MS
+            .iter()
+            .position(|&bound| latency_ms <= bound)
+            .unwrap_or(PIPELINE_LATENCY_BUCKET_BOUNDS_MS.len());
+        self.pipeline_latency_buckets[bucket].fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Approximate p50/p95/p99 end-to-end pipeline latency (ms), read off the bucket
+    /// histogram. Precision is bounded by bucket width, not exact — good enough to
+    /// tell "usually instant" from "usually five minutes late".
+    fn pipeline_latency_percentiles(&self) -> (u64, u64, u64) {
+        let total = self.pipeline_latency_count.load(Ordering::Relaxed);
+        if total == 0 {
+            return (0, 0, 0);
+        }
+        let percentile_ms = |p: f64| -> u64 {
+            let target = (total as f64 * p).ceil() as u64;
+            let mut cumulative = 0u64;
+            for (i, bound) in PIPELINE_LATENCY_BUCKET_BOUNDS_MS.iter().enumerate() {
+                cumulative += self.pipeline_latency_buckets[i].load(Ordering::Relaxed);
+                if cumulative >= target {
+                    return *bound;
+                }
+            }
+            *PIPELINE_LATENCY_BUCKET_BOUNDS_MS
+                .last()
+                .expect("bucket bounds non-empty")
+        };
+        (percentile_ms(0.50), percentile_ms(0.95), percentile_ms(0.99))
+    }
+
     /// Take a point-in-time snapshot for reporting.
     pub fn snapshot(&self) -> AudioMetricsSnapshot {
         let chunks_sent = self.chunks_sent.load(Ordering::Relaxed);
@@ -193,6 +328,27 @@ impl AudioPipelineMetrics {
         let transcriptions_completed = self.transcriptions_completed.load(Ordering::Relaxed);
         let db_inserted = self.db_inserted.load(Ordering::Relaxed);
         let uptime_secs = self.started_at.elapsed().as_secs_f64();
+        let (pipeline_latency_p50_ms, pipeline_latency_p95_ms, pipeline_latency_p99_ms) =
+            self.pipeline_latency_percentiles();
+        let reconciliation_started_at_ts = self.reconciliation_started_at_ts.load(Ordering::Relaxed);
+        let reconciliation_chunks_total = self.reconciliation_chunks_total.load(Ordering::Relaxed);
+        let reconciliation_chunks_done = self.reconciliation_chunks_done.load(Ordering::Relaxed);
+        let reconciliation_running = reconciliation_started_at_ts > 0;
+        let reconciliation_eta_secs = if reconciliation_running && reconciliation_chunks_done > 0 {
+            let now = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs();
+            let elapsed = now.saturating_sub(reconciliation_started_at_ts) as f64;
+            let remaining = reconciliation_chunks_total.saturating_sub(reconciliation_chunks_done) as f64;
+            Some(elapsed * remaining / reconciliation_chunks_done as f64)
+        } else {
+            None
+        };
+
+        let denoise_confidence_count = self.denoise_confidence_count.load(Ordering::Relaxed);
+        let raw_confidence_count = self.raw_confidence_count.load(Ordering::Relaxed);
+        let denoise_chunks_total = self.denoise_chunks_total.load(Ordering::Relaxed);
 
         AudioMetricsSnapshot {
             uptime_secs,
@@ -223,6 +379,37 @@ impl AudioPipelineMetrics {
             segments_batch_processed: self.segments_batch_processed.load(Ordering::Relaxed),
             batch_pause_events: self.batch_pause_events.load(Ordering::Relaxed),
             batch_resume_events: self.batch_resume_events.load(Ordering::Relaxed),
+            // Reconciliation
+            reconciliation_running,
+            reconciliation_chunks_total,
+            reconciliation_chunks_done,
+            reconciliation_eta_secs,
+            // Noise suppression (denoise)
+            denoise_chunks_total,
+            denoise_avg_cpu_micros: if denoise_chunks_total > 0 {
+                self.denoise_cpu_micros_total.load(Ordering::Relaxed) as f64
+                    / denoise_chunks_total as f64
+            } else {
+                0.0
+            },
+            denoise_avg_confidence: if denoise_confidence_count > 0 {
+                Some(
+                    self.denoise_confidence_sum_x1000.load(Ordering::Relaxed) as f64
+                        / denoise_confidence_count as f64
+                        / 1000.0,
+                )
+            } else {
+                None
+            },
+            raw_avg_confidence: if raw_confidence_count > 0 {
+                Some(
+                    self.raw_confidence_sum_x1000.load(Ordering::Relaxed) as f64
+                        / raw_confidence_count as f64
+                        / 1000.0,
+                )
+            } else {
+                None
+            },
             // Derived
             vad_passthrough_rate: if vad_total > 0 {
                 vad_passed as f64 / vad_total as f64
@@ -236,6 +423,17 @@ impl AudioPipelineMetrics {
             },
             audio_level_rms: self.audio_level_rms_x10000.load(Ordering::Relaxed) as f64 / 10000.0,
             last_db_write_ts: self.last_db_write_ts.load(Ordering::Relaxed),
+            pipeline_latency_p50_ms,
+            pipeline_latency_p95_ms,
+            pipeline_latency_p99_ms,
+            pipeline_latency_avg_ms: {
+                let count = self.pipeline_latency_count.load(Ordering::Relaxed);
+                if count > 0 {
+                    self.pipeline_latency_sum_ms.load(Ordering::Relaxed) as f64 / count as f64
+                } else {
+                    0.0
+                }
+            },
         }
     }
 }
@@ -278,6 +476,21 @@ pub struct AudioMetricsSnapshot {
     pub batch_pause_events: u64,
     pub batch_resume_events: u64,
 
+    // Deferred-chunk reconciliation progress
+    pub reconciliation_running: bool,
+    pub reconciliation_chunks_total: u64,
+    pub reconciliation_chunks_done: u64,
+    /// `None` until at least one chunk has completed this sweep (nothing to extrapolate from yet).
+    pub reconciliation_eta_secs: Option<f64>,
+
+    // Noise suppression (denoise) — CPU-budget + A/B accounting
+    pub denoise_chunks_total: u64,
+    pub denoise_avg_cpu_micros: f64,
+    /// Avg transcription confidence for denoised chunks (WER proxy). `None` if none yet.
+    pub denoise_avg_confidence: Option<f64>,
+    /// Avg transcription confidence for non-denoised chunks — the A/B baseline.
+    pub raw_avg_confidence: Option<f64>,
+
     // Derived
     /// 0.0 = all rejected, 1.0 = all passed
     pub vad_passthrough_rate: f64,
@@ -287,4 +500,10 @@ pub struct AudioMetricsSnapshot {
     pub audio_level_rms: f64,
     /// Unix timestamp (secs) of most recent DB insert (0 = none yet)
     pub last_db_write_ts: u64,
+
+    // End-to-end pipeline latency (capture -> DB insert), in ms
+    pub pipeline_latency_p50_ms: u64,
+    pub pipeline_latency_p95_ms: u64,
+    pub pipeline_latency_p99_ms: u64,
+    pub pipeline_latency_avg_ms: f64,
 }
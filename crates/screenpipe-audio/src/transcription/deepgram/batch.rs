@@ -96,7 +96,7 @@ fn create_query_params(languages: Vec<Language>, vocabulary: &[VocabularyEntry])
         let keyword = entry.replacement.as_deref().unwrap_or(&entry.word);
         // Simple percent-encode spaces for the query string
         let encoded = keyword.replace(' ', "%20");
-        query_params.push_str(&format!("&keywords={}:2", encoded));
+        query_params.push_str(&format!("&keywords={}:{}", encoded, entry.effective_boost()));
     }
 
     query_params
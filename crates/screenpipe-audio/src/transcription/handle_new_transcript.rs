@@ -8,6 +8,7 @@ use crate::{
     core::engine::AudioTranscriptionEngine, metrics::AudioPipelineMetrics,
     transcription::process_transcription_result,
 };
+use screenpipe_core::text_filters::TranscriptFilterConfig;
 use screenpipe_db::DatabaseManager;
 use tracing::{debug, error, info};
 
@@ -40,6 +41,7 @@ pub async fn handle_new_transcript(
     transcription_receiver: Arc<crossbeam::channel::Receiver<TranscriptionResult>>,
     transcription_engine: Arc<AudioTranscriptionEngine>,
     use_pii_removal: bool,
+    transcript_filter: TranscriptFilterConfig,
     metrics: Arc<AudioPipelineMetrics>,
     on_insert: Option<AudioInsertCallback>,
 ) {
@@ -135,6 +137,7 @@ pub async fn handle_new_transcript(
         let duration_secs = transcription.end_time - transcription.start_time;
         let insert_transcription = current_transcript.clone().unwrap_or_default();
         let capture_timestamp = transcription.input.capture_timestamp;
+        let correlation_id = transcription.input.correlation_id.clone();
 
         // Process the transcription result
         match process_transcription_result(
@@ -144,6 +147,7 @@ pub async fn handle_new_transcript(
             processed_previous,
             previous_transcript_id,
             use_pii_removal,
+            transcript_filter,
         )
         .await
         {
@@ -156,6 +160,16 @@ pub async fn handle_new_transcript(
                     prev_id_by_device.remove(&device_key);
                 }
                 metrics.record_db_insert(word_count as u64);
+                let now_secs = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map(|d| d.as_secs())
+                    .unwrap_or(capture_timestamp);
+                let latency_ms = now_secs.saturating_sub(capture_timestamp) * 1000;
+                metrics.record_pipeline_latency_ms(latency_ms);
+                debug!(
+                    correlation_id,
+                    latency_ms, "db insert stage complete, chunk pipeline finished"
+                );
 
                 if was_trimmed {
                     debug!(
@@ -19,6 +19,15 @@ pub struct AudioInput {
     /// Epoch seconds when the audio was captured (not when it was processed).
     /// Used to ensure correct timestamps in batch/deferred transcription mode.
     pub capture_timestamp: u64,
+    /// Opaque id that follows a chunk through segmentation, VAD, transcription, and DB
+    /// insert. Attached to tracing spans/events at each stage so a slow chunk can be
+    /// traced end-to-end (see `AudioPipelineMetrics::record_pipeline_latency_ms`).
+    pub correlation_id: String,
+}
+
+/// Generate a new correlation id for a freshly captured audio chunk.
+pub fn new_correlation_id() -> String {
+    format!("{:016x}", rand::random::<u64>())
 }
 
 /// A custom vocabulary entry for transcription biasing/correction.
@@ -28,6 +37,21 @@ pub struct AudioInput {
 pub struct VocabularyEntry {
     pub word: String,
     pub replacement: Option<String>,
+    /// How strongly to bias the decoder toward this term, roughly 1.0-5.0.
+    /// Deepgram: passed as the keyword's intensifier weight. Whisper: the word is
+    /// repeated proportionally in the initial_prompt (Whisper has no native boost
+    /// API). Defaults to `DEFAULT_VOCABULARY_BOOST` when unset.
+    #[serde(default)]
+    pub boost: Option<f32>,
+}
+
+/// Default boost weight applied to a vocabulary entry that doesn't specify one.
+pub const DEFAULT_VOCABULARY_BOOST: f32 = 2.0;
+
+impl VocabularyEntry {
+    pub fn effective_boost(&self) -> f32 {
+        self.boost.unwrap_or(DEFAULT_VOCABULARY_BOOST).clamp(1.0, 5.0)
+    }
 }
 
 pub mod text_utils;
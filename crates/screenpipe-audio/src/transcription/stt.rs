@@ -19,7 +19,7 @@ use screenpipe_core::Language;
 use std::path::PathBuf;
 use std::{sync::Arc, sync::Mutex as StdMutex};
 use tokio::sync::Mutex;
-use tracing::error;
+use tracing::{debug, error};
 use whisper_rs::WhisperState;
 
 use crate::transcription::VocabularyEntry;
@@ -58,7 +58,7 @@ pub async fn stt_sync(
     whisper_state: &mut WhisperState,
     vocabulary: &[VocabularyEntry],
     alternate_stt: Option<AlternateSttEngine>,
-) -> Result<String> {
+) -> Result<(String, Option<f32>)> {
     let audio = audio.to_vec();
 
     let device = device.to_string();
@@ -88,10 +88,13 @@ pub async fn stt(
     whisper_state: &mut WhisperState,
     vocabulary: &[VocabularyEntry],
     alternate_stt: Option<AlternateSttEngine>,
-) -> Result<String> {
-    let transcription: Result<String> =
+) -> Result<(String, Option<f32>)> {
+    // Confidence (average per-token probability) is only available from
+    // Whisper — Deepgram and Qwen3-ASR (via audiopipe) don't expose it
+    // through the APIs this crate integrates with.
+    let transcription: Result<(String, Option<f32>)> =
         if *audio_transcription_engine == AudioTranscriptionEngine::Disabled {
-            Ok(String::new())
+            Ok((String::new(), None))
         } else if audio_transcription_engine == AudioTranscriptionEngine::Deepgram.into() {
             // Deepgram implementation
             let api_key = deepgram_api_key.unwrap_or_default();
@@ -99,7 +102,7 @@ pub async fn stt(
             match transcribe_with_deepgram(&api_key, audio, device, sample_rate, languages.clone(), vocabulary)
                 .await
             {
-                Ok(transcription) => Ok(transcription),
+                Ok(transcription) => Ok((transcription, None)),
                 Err(e) => {
                     error!(
                         "device: {}, deepgram transcription failed, falling back to Whisper: {:?}",
@@ -113,7 +116,7 @@ pub async fn stt(
             // Qwen3-ASR via alternate STT engine (audiopipe)
             if let Some(ref engine) = alternate_stt {
                 let mut engine = engine.lock().map_err(|e| anyhow::anyhow!("stt model lock: {}", e))?;
-                engine.transcribe(audio, sample_rate)
+                engine.transcribe(audio, sample_rate).map(|text| (text, None))
             } else {
                 Err(anyhow::anyhow!("qwen3-asr model not initialized"))
             }
@@ -124,15 +127,15 @@ pub async fn stt(
 
     // Post-processing: apply vocabulary replacements
     match transcription {
-        Ok(mut text) => {
+        Ok((mut text, confidence)) => {
             for entry in vocabulary {
                 if let Some(ref replacement) = entry.replacement {
                     text = text.replace(&entry.word, replacement);
                 }
             }
-            Ok(text)
+            Ok((text, confidence))
         }
-        err => err,
+        Err(e) => Err(e),
     }
 }
 
@@ -153,25 +156,44 @@ pub async fn process_audio_input(
     vocabulary: &[VocabularyEntry],
     pre_written_path: Option<String>,
     alternate_stt: Option<AlternateSttEngine>,
+    denoise_enabled: bool,
 ) -> Result<()> {
     // NOTE: capture_timestamp is set when audio enters the channel, but smart mode
     // deferral can delay processing by 20+ minutes. The DB now uses Utc::now() at
     // insert time instead, so this timestamp is only used for TranscriptionResult
     // metadata (not for DB storage).
     let timestamp = audio.capture_timestamp;
+    let correlation_id = audio.correlation_id.clone();
 
     let audio_data = if audio.sample_rate != SAMPLE_RATE {
-        resample(audio.data.as_ref(), audio.sample_rate, SAMPLE_RATE)?
+        resample(
+            &audio.device.to_string(),
+            audio.data.as_ref(),
+            audio.sample_rate,
+            SAMPLE_RATE,
+        )?
     } else {
         audio.data.as_ref().to_vec()
     };
 
+    // Optional per-device noise suppression, applied before VAD/transcription
+    // (see crate::denoise for the CPU-budget/quality tradeoff this makes).
+    let audio_data = if denoise_enabled {
+        let started = std::time::Instant::now();
+        let denoised = crate::denoise::suppress_noise(&audio_data, SAMPLE_RATE);
+        metrics.record_denoise_cpu(started.elapsed());
+        denoised
+    } else {
+        audio_data
+    };
+
     let audio = AudioInput {
         data: Arc::new(audio_data.clone()),
         sample_rate: SAMPLE_RATE,
         channels: audio.channels,
         device: audio.device,
         capture_timestamp: audio.capture_timestamp,
+        correlation_id: correlation_id.clone(),
     };
 
     let is_output_device =
@@ -188,6 +210,10 @@ pub async fn process_audio_input(
     .await?;
 
     metrics.record_vad_result(speech_ratio_ok, speech_ratio);
+    debug!(
+        correlation_id,
+        speech_ratio_ok, speech_ratio, "vad stage complete"
+    );
 
     if !speech_ratio_ok {
         // Audio is already persisted to disk by the caller — just skip transcription
@@ -224,9 +250,14 @@ pub async fn process_audio_input(
             whisper_state,
             vocabulary,
             alternate_stt.clone(),
+            correlation_id.clone(),
         )
         .await?;
 
+        if let Some(confidence) = transcription_result.confidence {
+            metrics.record_ab_confidence(denoise_enabled, confidence);
+        }
+
         if output_sender.send(transcription_result).is_err() {
             break;
         }
@@ -247,6 +278,7 @@ pub async fn run_stt(
     whisper_state: &mut WhisperState,
     vocabulary: &[VocabularyEntry],
     alternate_stt: Option<AlternateSttEngine>,
+    correlation_id: String,
 ) -> Result<TranscriptionResult> {
     let audio = segment.samples.clone();
     let sample_rate = segment.sample_rate;
@@ -263,24 +295,29 @@ pub async fn run_stt(
     )
     .await
     {
-        Ok(transcription) => Ok(TranscriptionResult {
-            input: AudioInput {
-                data: Arc::new(audio),
-                sample_rate,
-                channels: 1,
-                device: device.clone(),
-                capture_timestamp: timestamp,
-            },
-            transcription: Some(transcription),
-            path,
-            timestamp,
-            error: None,
-            speaker_embedding: segment.embedding.clone(),
-            start_time: segment.start,
-            end_time: segment.end,
-        }),
+        Ok((transcription, confidence)) => {
+            debug!(correlation_id, "whisper stage complete");
+            Ok(TranscriptionResult {
+                input: AudioInput {
+                    data: Arc::new(audio),
+                    sample_rate,
+                    channels: 1,
+                    device: device.clone(),
+                    capture_timestamp: timestamp,
+                    correlation_id,
+                },
+                transcription: Some(transcription),
+                path,
+                timestamp,
+                error: None,
+                speaker_embedding: segment.embedding.clone(),
+                start_time: segment.start,
+                end_time: segment.end,
+                confidence,
+            })
+        }
         Err(e) => {
-            error!("STT error for input {}: {:?}", device, e);
+            error!(correlation_id, "STT error for input {}: {:?}", device, e);
             Ok(TranscriptionResult {
                 input: AudioInput {
                     data: Arc::new(segment.samples),
@@ -288,6 +325,7 @@ pub async fn run_stt(
                     channels: 1,
                     device: device.clone(),
                     capture_timestamp: timestamp,
+                    correlation_id,
                 },
                 transcription: None,
                 path,
@@ -296,6 +334,7 @@ pub async fn run_stt(
                 speaker_embedding: Vec::new(),
                 start_time: segment.start,
                 end_time: segment.end,
+                confidence: None,
             })
         }
     }
@@ -6,9 +6,12 @@ use std::sync::Arc;
 
 use chrono::{DateTime, Utc};
 use screenpipe_core::pii_removal::remove_pii;
+use screenpipe_core::text_filters::TranscriptFilterConfig;
+use screenpipe_core::text_normalization::normalize_transcript;
 use screenpipe_db::{DatabaseManager, Speaker};
 use tracing::{debug, error};
 
+use crate::core::device_quality;
 use crate::core::engine::AudioTranscriptionEngine;
 
 use super::{text_utils::longest_common_word_substring, AudioInput};
@@ -23,6 +26,10 @@ pub struct TranscriptionResult {
     pub error: Option<String>,
     pub start_time: f64,
     pub end_time: f64,
+    /// Average per-token probability from Whisper (`0.0`-`1.0`, higher is
+    /// more confident), or `None` for engines that don't expose per-token
+    /// probabilities (Deepgram, Qwen3-ASR).
+    pub confidence: Option<f32>,
 }
 
 impl TranscriptionResult {
@@ -68,6 +75,7 @@ pub async fn process_transcription_result(
     previous_transcript: Option<String>,
     previous_transcript_id: Option<i64>,
     use_pii_removal: bool,
+    transcript_filter: TranscriptFilterConfig,
 ) -> Result<Option<AudioInsertResult>, anyhow::Error> {
     if result.error.is_some() || result.transcription.is_none() {
         error!(
@@ -81,12 +89,36 @@ pub async fn process_transcription_result(
 
     debug!("detected speaker id={}", speaker.id);
 
+    // A speaker hidden via the speaker management API is "never record" — drop
+    // the transcript instead of persisting it.
+    if db.is_speaker_hidden(speaker.id).await.unwrap_or(false) {
+        debug!("speaker {} is hidden, not inserting transcript", speaker.id);
+        return Ok(None);
+    }
+
     let raw_transcription = result.transcription.unwrap();
-    // Apply PII removal if enabled
+    // Apply the profanity/filler-word filter before PII removal, then PII removal,
+    // so a "fuck my SSN is ..." transcript still gets both redactions.
+    let filtered_transcription = transcript_filter.apply(&raw_transcription);
     let transcription = if use_pii_removal {
-        remove_pii(&raw_transcription)
+        remove_pii(&filtered_transcription)
     } else {
-        raw_transcription
+        filtered_transcription.clone()
+    };
+    // Only keep the raw copy when the filter actually changed something — avoids
+    // doubling storage for the (default) unfiltered case.
+    let stored_raw_transcription = if transcript_filter.is_noop() || filtered_transcription == raw_transcription {
+        None
+    } else {
+        Some(raw_transcription)
+    };
+    // Written-form (ITN) copy for search, e.g. "five thirty pm" -> "5:30 PM".
+    // Only stored when normalization actually changed something.
+    let normalized = normalize_transcript(&transcription);
+    let stored_normalized_transcription = if normalized == transcription {
+        None
+    } else {
+        Some(normalized)
     };
     let transcription_engine = audio_transcription_engine.to_string();
     let speaker_id = Some(speaker.id);
@@ -123,6 +155,15 @@ pub async fn process_transcription_result(
     // if smart mode deferred transcription by 20 minutes.
     let capture_ts: Option<DateTime<Utc>> = DateTime::from_timestamp(result.timestamp as i64, 0);
 
+    // Tag the transcript when it was captured while the device was running in a
+    // degraded audio profile (e.g. a Bluetooth headset fallen back to narrowband
+    // HFP mid-call), so the UI can explain otherwise-unexplained quality drops.
+    let audio_quality_flag = if device_quality::is_degraded(&result.input.device.to_string()) {
+        Some("degraded_bluetooth_hfp")
+    } else {
+        None
+    };
+
     match db
         .insert_audio_chunk_and_transcription(
             &result.path,
@@ -140,6 +181,10 @@ pub async fn process_transcription_result(
             Some(result.start_time),
             Some(result.end_time),
             capture_ts,
+            stored_raw_transcription.as_deref(),
+            stored_normalized_transcription.as_deref(),
+            audio_quality_flag,
+            result.confidence.map(|c| c as f64),
         )
         .await
     {
@@ -19,20 +19,22 @@ const MIN_RMS_ENERGY: f32 = 0.015;
 /// Processes audio data using the Whisper model to generate transcriptions.
 ///
 /// # Returns
-/// A string containing the processed transcript
+/// The transcript, plus the average per-token probability across all
+/// segments (`0.0`-`1.0`, higher is more confident) — `None` when there
+/// were no tokens to average (e.g. the RMS pre-check short-circuited).
 pub async fn process_with_whisper(
     audio: &[f32],
     languages: Vec<Language>,
     whisper_state: &mut WhisperState,
     vocabulary: &[VocabularyEntry],
-) -> Result<String> {
+) -> Result<(String, Option<f32>)> {
     // Pre-check: if audio energy is too low, skip transcription entirely.
     // Whisper hallucinates on silence/near-silence (e.g. "Thank you.", "So, let's go.")
     // and its internal no_speech_prob is unreliable (reports 0.0 on pure silence).
     let rms = (audio.iter().map(|s| s * s).sum::<f32>() / audio.len() as f32).sqrt();
     if rms < MIN_RMS_ENERGY {
         debug!("audio RMS {:.6} below threshold {:.6}, skipping whisper", rms, MIN_RMS_ENERGY);
-        return Ok(String::new());
+        return Ok((String::new(), None));
     }
 
     let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
@@ -70,9 +72,14 @@ pub async fn process_with_whisper(
 
     // Set initial_prompt from vocabulary to bias Whisper toward custom words
     if !vocabulary.is_empty() {
+        // Whisper has no native keyword-boost API, so higher-boost terms are simply
+        // repeated more times in the prompt — a common informal biasing trick.
         let prompt: String = vocabulary
             .iter()
-            .map(|v| v.replacement.as_deref().unwrap_or(&v.word))
+            .flat_map(|v| {
+                let word = v.replacement.as_deref().unwrap_or(&v.word);
+                std::iter::repeat(word).take(v.effective_boost().round() as usize)
+            })
             .collect::<Vec<_>>()
             .join(", ");
         // Truncate to ~800 chars to stay within Whisper's 224 token limit
@@ -92,6 +99,8 @@ pub async fn process_with_whisper(
     let num_segments = whisper_state.full_n_segments();
 
     let mut transcript = String::new();
+    let mut prob_sum = 0.0f64;
+    let mut token_count = 0u32;
 
     for i in 0..num_segments {
         if let Some(segment) = whisper_state.get_segment(i) {
@@ -99,7 +108,22 @@ pub async fn process_with_whisper(
                 transcript.push_str(text);
             }
         }
+        // Average per-token probability, used as a stand-in for Whisper's
+        // avg-logprob confidence — best-effort, skipped for any token whisper.cpp
+        // can't produce data for rather than failing the whole transcription.
+        for t in 0..whisper_state.full_n_tokens(i) {
+            if let Ok(token_data) = whisper_state.full_get_token_data(i, t) {
+                prob_sum += token_data.p as f64;
+                token_count += 1;
+            }
+        }
     }
 
-    Ok(transcript)
+    let confidence = if token_count > 0 {
+        Some((prob_sum / token_count as f64) as f32)
+    } else {
+        None
+    };
+
+    Ok((transcript, confidence))
 }
@@ -89,6 +89,106 @@ fn encode_single_audio(
     Ok(())
 }
 
+/// Container/codec formats supported by the audio export endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Wav,
+    Opus,
+    Mp3,
+}
+
+impl ExportFormat {
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        match ext.to_ascii_lowercase().as_str() {
+            "wav" => Some(Self::Wav),
+            "opus" => Some(Self::Opus),
+            "mp3" => Some(Self::Mp3),
+            _ => None,
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Wav => "wav",
+            Self::Opus => "opus",
+            Self::Mp3 => "mp3",
+        }
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Wav => "audio/wav",
+            Self::Opus => "audio/opus",
+            Self::Mp3 => "audio/mpeg",
+        }
+    }
+}
+
+/// Encode a single-channel f32 PCM buffer to the requested export format. Used by the
+/// audio export endpoint to produce a downloadable file from stitched-together chunks
+/// (see `screenpipe-server`'s `routes::audio_export`), as opposed to `encode_single_audio` which
+/// always writes the internal AAC/mp4 storage format.
+pub fn encode_export_audio(
+    samples: &[f32],
+    sample_rate: u32,
+    format: ExportFormat,
+    output_path: &Path,
+) -> Result<()> {
+    let mut command = Command::new(find_ffmpeg_path().unwrap());
+    command.args([
+        "-f",
+        "f32le",
+        "-ar",
+        &sample_rate.to_string(),
+        "-ac",
+        "1",
+        "-i",
+        "pipe:0",
+    ]);
+    match format {
+        ExportFormat::Wav => {
+            command.args(["-c:a", "pcm_s16le", "-f", "wav"]);
+        }
+        ExportFormat::Opus => {
+            command.args(["-c:a", "libopus", "-b:a", "96k", "-f", "opus"]);
+        }
+        ExportFormat::Mp3 => {
+            command.args(["-c:a", "libmp3lame", "-b:a", "128k", "-f", "mp3"]);
+        }
+    }
+    command
+        .arg(output_path.to_str().unwrap())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    #[allow(clippy::zombie_processes)]
+    let mut ffmpeg = command.spawn().map_err(|e| anyhow::anyhow!("failed to spawn ffmpeg: {}", e))?;
+    let mut stdin = ffmpeg.stdin.take().expect("Failed to open stdin");
+    stdin.write_all(bytemuck::cast_slice(samples))?;
+    drop(stdin);
+
+    let output = ffmpeg.wait_with_output()?;
+    if !output.status.success() {
+        error!(
+            "ffmpeg export failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        return Err(anyhow::anyhow!(
+            "ffmpeg export process failed with status: {}",
+            output.status
+        ));
+    }
+    Ok(())
+}
+
 pub fn get_new_file_path(device: &str, output_path: &PathBuf) -> String {
     let new_file_name = Utc::now().format("%Y-%m-%d_%H-%M-%S").to_string();
     let sanitized_device_name = device.replace(['/', '\\'], "_");
@@ -99,11 +199,40 @@ pub fn get_new_file_path(device: &str, output_path: &PathBuf) -> String {
         .to_string()
 }
 
+/// If `path` is an encrypted media file (see `screenpipe_core::media_crypto`),
+/// decrypts it to a temp file and returns that path — ffmpeg needs a real
+/// path to read from, not a byte buffer. The returned `TempDir` must be kept
+/// alive by the caller until ffmpeg is done with the path; it deletes the
+/// plaintext copy when dropped. Plain (unencrypted) files pass through
+/// untouched, which is the common case since media encryption is opt-in.
+fn maybe_decrypt_to_temp(path: &Path) -> Result<(PathBuf, Option<tempfile::TempDir>)> {
+    use screenpipe_core::media_crypto::{decrypt_media, is_encrypted, resolve_media_key, MediaKeySource};
+
+    let bytes = std::fs::read(path)?;
+    if !is_encrypted(&bytes) {
+        return Ok((path.to_path_buf(), None));
+    }
+
+    let key = resolve_media_key(&MediaKeySource::Keychain)
+        .map_err(|e| anyhow::anyhow!("failed to resolve media encryption key: {}", e))?;
+    let plaintext =
+        decrypt_media(&bytes, &key).map_err(|e| anyhow::anyhow!("failed to decrypt media file: {}", e))?;
+
+    let dir = tempfile::tempdir()?;
+    let file_name = path.file_name().unwrap_or_else(|| std::ffi::OsStr::new("decrypted"));
+    let temp_path = dir.path().join(file_name);
+    std::fs::write(&temp_path, plaintext)?;
+    Ok((temp_path, Some(dir)))
+}
+
 /// Decode an audio file (MP4/AAC) back to 16kHz mono f32 PCM using ffmpeg.
 /// Returns (samples, sample_rate).
 pub fn read_audio_from_file(path: &Path) -> Result<(Vec<f32>, u32)> {
     let sample_rate: u32 = 16000;
 
+    let (decrypted_path, _temp_dir) = maybe_decrypt_to_temp(path)?;
+    let path = decrypted_path.as_path();
+
     let mut command = Command::new(find_ffmpeg_path().unwrap());
     command
         .args([
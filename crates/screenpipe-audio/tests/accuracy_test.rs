@@ -103,10 +103,12 @@ async fn test_transcription_accuracy() {
                 channels: 1,
                 device: Arc::new(default_input_device().unwrap()),
                 capture_timestamp: 0,
+                correlation_id: "test".to_string(),
             };
 
             let audio_data = if audio_input.sample_rate != SAMPLE_RATE {
                 match resample(
+                    "accuracy-test-device",
                     audio_input.data.as_ref(),
                     audio_input.sample_rate,
                     SAMPLE_RATE,
@@ -297,7 +297,7 @@ async fn pipeline_with_whisper_dataset() {
                 )
                 .await
                 {
-                    Ok(text) => {
+                    Ok((text, _confidence)) => {
                         if !text.is_empty() {
                             let wr = word_recall(&gt_combined, &text);
                             channel_recall_sum += wr;
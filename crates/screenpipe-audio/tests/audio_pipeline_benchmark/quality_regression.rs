@@ -43,7 +43,7 @@ async fn whisper_hallucination_on_silence() {
     // 30 seconds of pure silence at 16kHz
     let silence = vec![0.0f32; SAMPLE_RATE as usize * 30];
 
-    let text = screenpipe_audio::stt(
+    let (text, _confidence) = screenpipe_audio::stt(
         &silence,
         SAMPLE_RATE,
         "test-silence",
@@ -90,7 +90,7 @@ async fn whisper_hallucination_on_noise() {
     // 30 seconds of white noise at 0.1 amplitude
     let noise = audio_fixtures::white_noise(0.1, 30.0);
 
-    let text = screenpipe_audio::stt(
+    let (text, _confidence) = screenpipe_audio::stt(
         &noise,
         SAMPLE_RATE,
         "test-noise",
@@ -135,7 +135,7 @@ async fn whisper_hallucination_on_ambient() {
     // 30s of very low noise at 0.01 amplitude (typical quiet room)
     let ambient = audio_fixtures::white_noise(0.01, 30.0);
 
-    let text = screenpipe_audio::stt(
+    let (text, _confidence) = screenpipe_audio::stt(
         &ambient,
         SAMPLE_RATE,
         "test-ambient",
@@ -241,7 +241,7 @@ async fn whisper_real_speech_still_works() {
             let gt_combined = gt_texts.join(" ");
             let gt_word_count = gt_combined.split_whitespace().count();
 
-            let text = screenpipe_audio::stt(
+            let (text, _confidence) = screenpipe_audio::stt(
                 chunk,
                 SAMPLE_RATE,
                 "benchmark",
@@ -78,6 +78,7 @@ mod tests {
             Arc::new(sender),
             is_running,
             Arc::new(screenpipe_audio::metrics::AudioPipelineMetrics::new()),
+            None,
         )
         .await;
         println!("record_and_transcribe completed");
@@ -145,6 +146,7 @@ mod tests {
             Arc::new(sender),
             is_running,
             Arc::new(screenpipe_audio::metrics::AudioPipelineMetrics::new()),
+            None,
         )
         .await
         .unwrap();
@@ -237,6 +239,7 @@ mod tests {
             channels: 1,
             device: Arc::new(default_input_device().unwrap()),
             capture_timestamp: 0,
+            correlation_id: "test".to_string(),
         };
 
         // Create the missing parameters
@@ -325,6 +328,7 @@ mod tests {
             channels: 1,
             device: Arc::new(default_output_device().await.unwrap()),
             capture_timestamp: 0,
+            correlation_id: "test".to_string(),
         };
 
         let project_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
@@ -169,6 +169,7 @@ mod tests {
         // reesample if not 16000
         if multiple_speakers_sample_rate != 16000 {
             multiple_speakers_data = screenpipe_audio::resample(
+                "speaker-id-test-device",
                 &multiple_speakers_data,
                 multiple_speakers_sample_rate,
                 16000,
@@ -184,7 +185,9 @@ mod tests {
 
         // reesample if not 16000
         if obama_sample_rate != 16000 {
-            obama_data = screenpipe_audio::resample(&obama_data, obama_sample_rate, 16000).unwrap();
+            obama_data =
+                screenpipe_audio::resample("speaker-id-test-device", &obama_data, obama_sample_rate, 16000)
+                    .unwrap();
         }
 
         multiple_speakers_data.extend_from_slice(&obama_data);
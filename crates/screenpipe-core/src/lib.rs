@@ -13,8 +13,16 @@ pub mod pii_removal;
 #[cfg(feature = "security")]
 pub use pii_removal::*;
 
+#[cfg(feature = "security")]
+pub mod text_filters;
+#[cfg(feature = "security")]
+pub mod text_normalization;
+
 pub use language::{Language, TESSERACT_LANGUAGES};
 #[cfg(feature = "cloud-sync")]
 pub mod sync;
 #[cfg(feature = "cloud-sync")]
 pub use sync::*;
+
+#[cfg(feature = "media-encryption")]
+pub mod media_crypto;
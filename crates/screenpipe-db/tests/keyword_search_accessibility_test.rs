@@ -100,6 +100,10 @@ mod tests {
                 Order::Descending,
                 None,
                 None,
+                None,
+                None,
+                false,
+                None,
             )
             .await
             .unwrap();
@@ -129,6 +133,10 @@ mod tests {
                 Order::Descending,
                 None,
                 None,
+                None,
+                None,
+                false,
+                None,
             )
             .await
             .unwrap();
@@ -167,6 +175,10 @@ mod tests {
                 Order::Descending,
                 None,
                 None,
+                None,
+                None,
+                false,
+                None,
             )
             .await
             .unwrap();
@@ -209,6 +221,10 @@ mod tests {
                 Order::Descending,
                 None,
                 None,
+                None,
+                None,
+                false,
+                None,
             )
             .await
             .unwrap();
@@ -90,6 +90,10 @@ mod tests {
                 Order::Descending,
                 None,
                 None,
+                None,
+                None,
+                false,
+                None,
             )
             .await
             .unwrap();
@@ -145,6 +149,10 @@ mod tests {
                 Order::Ascending,
                 None,
                 None,
+                None,
+                None,
+                false,
+                None,
             )
             .await
             .unwrap();
@@ -201,6 +209,10 @@ mod tests {
                 Order::Descending,
                 None,
                 None,
+                None,
+                None,
+                false,
+                None,
             )
             .await
             .unwrap();
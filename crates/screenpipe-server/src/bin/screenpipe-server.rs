@@ -28,9 +28,24 @@ use screenpipe_server::{
         get_or_create_machine_id, AudioCommand, Cli, CliAudioTranscriptionEngine, Command,
         McpCommand, OutputFormat, RecordArgs, SyncCommand, VisionCommand,
     },
+    cli_backup::handle_backup_command,
+    cli_demo::handle_demo_command,
+    cli_devices::handle_devices_command,
+    cli_doctor::handle_doctor_command,
+    cli_encrypt::handle_encrypt_command,
+    cli_export::{handle_export_command, handle_export_stats_command},
+    cli_gc::handle_gc_command,
     cli_pipe::handle_pipe_command,
+    cli_profile::handle_profile_command,
+    cli_retranscribe::handle_retranscribe_command,
+    cli_search::handle_search_command,
+    cli_service::handle_service_command,
+    cli_shard::handle_shard_command,
     cli_status::handle_status_command,
+    grpc::{serve_grpc, GrpcState},
     hot_frame_cache::HotFrameCache,
+    profile::resolve_profile_dir,
+    service_notify,
     start_meeting_persister, start_meeting_watcher, start_sleep_monitor,
     start_speaker_identification, start_ui_recording,
     sync_provider::ScreenpipeSyncProvider,
@@ -43,10 +58,12 @@ use serde_json::json;
 use std::path::Path;
 use std::{
     env, fs,
+    future::Future,
     net::SocketAddr,
     net::{IpAddr, Ipv4Addr},
     ops::Deref,
     path::PathBuf,
+    pin::Pin,
     sync::Arc,
     time::Duration,
 };
@@ -211,6 +228,7 @@ fn setup_logging(local_data_dir: &PathBuf, cli: &Cli) -> anyhow::Result<WorkerGu
             fmt::layer()
                 .with_writer(std::io::stdout)
                 .with_timer(timer.clone())
+                .with_ansi(!cli.service)
                 .with_filter(make_env_filter()),
         )
         .with(
@@ -229,6 +247,23 @@ fn setup_logging(local_data_dir: &PathBuf, cli: &Cli) -> anyhow::Result<WorkerGu
         ),
     );
 
+    #[cfg(feature = "otel")]
+    let tracing_registry = match cli.otel_endpoint.as_deref() {
+        Some(endpoint) => match screenpipe_server::otel::build_layer(endpoint) {
+            Ok(layer) => tracing_registry.with(Some(layer)),
+            Err(e) => {
+                eprintln!("warning: failed to initialize OTLP trace export: {}", e);
+                tracing_registry.with(None)
+            }
+        },
+        None => tracing_registry.with(None),
+    };
+
+    #[cfg(not(feature = "otel"))]
+    if let Some(endpoint) = cli.otel_endpoint.as_deref() {
+        screenpipe_server::otel::warn_not_compiled_in(endpoint);
+    }
+
     // Build the final registry with conditional Sentry layer
     if !cli.disable_telemetry {
         tracing_registry
@@ -355,6 +390,7 @@ async fn main() -> anyhow::Result<()> {
     };
 
     let local_data_dir = get_base_dir(&cli.data_dir)?;
+    let local_data_dir = resolve_profile_dir(&local_data_dir, &cli.profile)?;
     let local_data_dir_clone = local_data_dir.clone();
 
     // Only set up logging if we're not running a command with JSON output
@@ -446,6 +482,90 @@ async fn main() -> anyhow::Result<()> {
                 handle_sync_command(subcommand).await?;
                 return Ok(());
             }
+            Command::Backup { subcommand } => {
+                handle_backup_command(subcommand).await?;
+                return Ok(());
+            }
+            Command::Shard { subcommand } => {
+                handle_shard_command(subcommand).await?;
+                return Ok(());
+            }
+            Command::Encrypt { subcommand } => {
+                handle_encrypt_command(subcommand).await?;
+                return Ok(());
+            }
+            Command::Gc {
+                grace_period_hours,
+                data_dir,
+            } => {
+                handle_gc_command(*grace_period_hours, data_dir).await?;
+                return Ok(());
+            }
+            Command::Doctor { json, data_dir } => {
+                handle_doctor_command(*json, data_dir).await?;
+                return Ok(());
+            }
+            Command::Demo {
+                data_dir,
+                days,
+                events_per_day,
+            } => {
+                handle_demo_command(data_dir, *days, *events_per_day).await?;
+                return Ok(());
+            }
+            Command::Profile { subcommand } => {
+                handle_profile_command(subcommand).await?;
+                return Ok(());
+            }
+            Command::Search {
+                query,
+                output,
+                limit,
+                port,
+                data_dir,
+            } => {
+                handle_search_command(query, output, *limit, *port, data_dir).await?;
+                return Ok(());
+            }
+            Command::Export {
+                query,
+                dest_path,
+                format,
+                limit,
+                data_dir,
+            } => {
+                handle_export_command(query, dest_path, format, *limit, data_dir).await?;
+                return Ok(());
+            }
+            Command::ExportStats {
+                start,
+                end,
+                dest_path,
+                format,
+                noise_scale,
+                data_dir,
+            } => {
+                handle_export_stats_command(start, end, dest_path, format, *noise_scale, data_dir)
+                    .await?;
+                return Ok(());
+            }
+            Command::Devices { output } => {
+                handle_devices_command(output).await?;
+                return Ok(());
+            }
+            Command::Retranscribe {
+                start,
+                end,
+                engine,
+                port,
+            } => {
+                handle_retranscribe_command(start, end, engine, *port).await?;
+                return Ok(());
+            }
+            Command::Service { subcommand } => {
+                handle_service_command(subcommand).await?;
+                return Ok(());
+            }
         }
     }
 
@@ -474,6 +594,7 @@ async fn main() -> anyhow::Result<()> {
     cli.audio_device = record_args.audio_device.clone();
     cli.use_system_default_audio = record_args.use_system_default_audio;
     cli.data_dir = record_args.data_dir.clone();
+    cli.profile = record_args.profile.clone();
     cli.debug = record_args.debug;
     cli.audio_transcription_engine = record_args.audio_transcription_engine.clone();
     cli.monitor_id = record_args.monitor_id.clone();
@@ -496,9 +617,14 @@ async fn main() -> anyhow::Result<()> {
     cli.sync_password = record_args.sync_password.clone();
     cli.sync_interval_secs = record_args.sync_interval_secs;
     cli.sync_machine_id = record_args.sync_machine_id.clone();
+    cli.otel_endpoint = record_args.otel_endpoint.clone();
+    cli.service = record_args.service;
+    cli.require_auth = record_args.require_auth;
+    cli.auth_rate_limit_per_minute = record_args.auth_rate_limit_per_minute;
 
     // Recompute data dir in case record_args overrode it
     let local_data_dir = get_base_dir(&cli.data_dir)?;
+    let local_data_dir = resolve_profile_dir(&local_data_dir, &cli.profile)?;
     let local_data_dir_clone = local_data_dir.clone();
 
     // Build unified RecordingConfig from CLI args
@@ -661,6 +787,7 @@ async fn main() -> anyhow::Result<()> {
             // In smart/batch mode, transcription can be deferred by minutes — using
             // Utc::now() would place audio far from the frames it belongs to.
             manager.set_on_transcription_insert(std::sync::Arc::new(move |info| {
+                screenpipe_server::realtime_captions::broadcast(&info);
                 let cache = cache.clone();
                 let ts = chrono::DateTime::from_timestamp(info.capture_timestamp as i64, 0)
                     .unwrap_or_else(chrono::Utc::now);
@@ -695,7 +822,7 @@ async fn main() -> anyhow::Result<()> {
     let vision_metrics = Arc::new(screenpipe_vision::PipelineMetrics::new());
 
     // Create VisionManager for event-driven capture on all monitors
-    let (handle, capture_trigger_tx) = if !config.disable_vision {
+    let (handle, capture_trigger_tx, vision_manager_for_grpc) = if !config.disable_vision {
         let vision_config =
             config.to_vision_manager_config(output_path_clone.to_string(), vision_metrics.clone());
         let vision_manager = Arc::new(
@@ -735,10 +862,10 @@ async fn main() -> anyhow::Result<()> {
                 error!("Error shutting down VisionManager: {:?}", e);
             }
         });
-        (h, Some(trigger_tx))
+        (h, Some(trigger_tx), Some(vision_manager))
     } else {
         // Vision disabled — spawn a no-op task so `handle` always exists
-        (tokio::spawn(async {}), None)
+        (tokio::spawn(async {}), None, None)
     };
 
     let local_data_dir_clone_2 = local_data_dir_clone.clone();
@@ -755,7 +882,27 @@ async fn main() -> anyhow::Result<()> {
     );
     server.vision_metrics = vision_metrics;
     server.audio_metrics = audio_manager.metrics.clone();
-    server.hot_frame_cache = Some(hot_frame_cache);
+    server.hot_frame_cache = Some(hot_frame_cache.clone());
+    server.resource_governor = Some(screenpipe_server::resource_governor::ResourceGovernorManager::spawn(
+        vision_manager_for_grpc.clone(),
+    ));
+    server.battery_policy = Some(screenpipe_server::battery_policy::BatteryPolicyManager::spawn(
+        vision_manager_for_grpc.clone(),
+    ));
+    if cli.require_auth {
+        let auth_manager = screenpipe_server::auth::AuthManager::spawn(
+            &local_data_dir,
+            screenpipe_server::auth::AuthConfig {
+                rate_limit_per_minute: cli.auth_rate_limit_per_minute,
+            },
+        )
+        .await;
+        tracing::info!(
+            "local API auth enabled, send this as \"Authorization: Bearer <token>\" — token: {}",
+            auth_manager.token()
+        );
+        server.auth = Some(auth_manager);
+    }
 
     // Attach sync handle if sync is enabled
     let server = if let Some(ref handle) = sync_service_handle {
@@ -1043,7 +1190,17 @@ async fn main() -> anyhow::Result<()> {
     let ui_recorder_handle = {
         if ui_recorder_config.enabled {
             info!("starting UI event capture");
-            match start_ui_recording(db.clone(), ui_recorder_config, capture_trigger_tx).await {
+            let ui_events_journal_dir =
+                PathBuf::from(output_path_clone.to_string()).join(".ui_events_journal");
+            match start_ui_recording(
+                db.clone(),
+                ui_recorder_config,
+                capture_trigger_tx,
+                ui_events_journal_dir,
+                Some(hot_frame_cache.clone()),
+            )
+            .await
+            {
                 Ok(handle) => Some(handle),
                 Err(e) => {
                     error!("failed to start UI event recording: {}", e);
@@ -1072,14 +1229,60 @@ async fn main() -> anyhow::Result<()> {
         .as_ref()
         .map(|detector| screenpipe_server::start_calendar_bridge(detector.clone()));
 
+    // Enable replay for topics consumers care about not missing across a
+    // brief disconnect — see screenpipe_events::EventManager::register_topic.
+    screenpipe_events::register_topic("calendar_events", screenpipe_events::TopicConfig::default());
+    screenpipe_events::register_topic("meeting_started", screenpipe_events::TopicConfig::default());
+    screenpipe_events::register_topic("meeting_ended", screenpipe_events::TopicConfig::default());
+
     // Start calendar-assisted speaker identification
     let _speaker_id_handle = start_speaker_identification(db.clone(), config.user_name.clone());
 
     // Periodic WAL checkpoint to prevent unbounded WAL growth
     db.start_wal_maintenance();
 
-    let server_future = server.start();
-    pin_mut!(server_future);
+    // Start the gRPC control-plane API, if a port was configured
+    if let Some(grpc_port) = config.grpc_port {
+        let grpc_state = Arc::new(GrpcState {
+            db: db.clone(),
+            audio_manager: audio_manager.clone(),
+            vision_manager: vision_manager_for_grpc.clone(),
+            vision_disabled: config.disable_vision,
+            audio_disabled: config.disable_audio,
+            ui_capture_enabled: ui_recorder_config.enabled,
+        });
+        let grpc_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), grpc_port);
+        tokio::spawn(async move {
+            if let Err(e) = serve_grpc(grpc_state, grpc_addr).await {
+                error!("gRPC control-plane server stopped with error: {:?}", e);
+            }
+        });
+    }
+
+    // Start the WASM plugin sandbox, if compiled in. Plugins live under
+    // <data_dir>/plugins/<plugin_name>/{plugin.wasm,plugin.yaml}.
+    #[cfg(feature = "wasm-plugins")]
+    let _plugin_host = screenpipe_server::plugin_host::PluginHost::spawn(local_data_dir.join("plugins"));
+
+    // Under systemd socket activation ($LISTEN_FDS set by the .socket unit),
+    // serve on the fd systemd already bound instead of binding our own, so
+    // the socket exists (and can queue connections) before we're even
+    // scheduled to run.
+    let server_future: Pin<Box<dyn Future<Output = Result<(), std::io::Error>> + Send>> =
+        match service_notify::socket_activation_listener() {
+            Some(Ok(listener)) => {
+                info!("using systemd-activated socket");
+                Box::pin(server.start_with_listener(listener))
+            }
+            Some(Err(e)) => {
+                warn!(
+                    "systemd socket activation looked active but failed ({}), binding normally",
+                    e
+                );
+                Box::pin(server.start())
+            }
+            None => Box::pin(server.start()),
+        };
 
     // Add auto-destruct watcher
     if let Some(pid) = cli.auto_destruct_pid {
@@ -1095,8 +1298,29 @@ async fn main() -> anyhow::Result<()> {
         });
     }
 
-    let ctrl_c_future = signal::ctrl_c();
-    pin_mut!(ctrl_c_future);
+    // Race ctrl+c against SIGTERM (the signal systemd sends on `systemctl
+    // stop`) so both trigger the same graceful shutdown path below.
+    let shutdown_signal = async {
+        #[cfg(unix)]
+        {
+            let mut sigterm = signal::unix::signal(signal::unix::SignalKind::terminate())
+                .expect("failed to install SIGTERM handler");
+            tokio::select! {
+                _ = signal::ctrl_c() => "ctrl+c",
+                _ = sigterm.recv() => "SIGTERM",
+            }
+        }
+        #[cfg(not(unix))]
+        {
+            let _ = signal::ctrl_c().await;
+            "ctrl+c"
+        }
+    };
+    pin_mut!(shutdown_signal);
+
+    if cli.service {
+        service_notify::notify_ready();
+    }
 
     tokio::select! {
         _ = handle => info!("recording completed"),
@@ -1106,8 +1330,11 @@ async fn main() -> anyhow::Result<()> {
                 Err(e) => error!("server stopped with error: {:?}", e),
             }
         }
-        _ = ctrl_c_future => {
-            info!("received ctrl+c, initiating shutdown");
+        reason = &mut shutdown_signal => {
+            info!("received {}, initiating shutdown", reason);
+            if cli.service {
+                service_notify::notify_stopping();
+            }
             audio_manager.shutdown().await?;
             // Stop UI recorder if running
             if let Some(ref handle) = ui_recorder_handle {
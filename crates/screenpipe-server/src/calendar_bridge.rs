@@ -28,6 +28,8 @@ struct CalendarEventItem {
     end: String,
     attendees: Vec<String>,
     is_all_day: bool,
+    #[serde(default)]
+    meeting_url: Option<String>,
 }
 
 /// Start the calendar bridge background task.
@@ -69,6 +71,7 @@ pub fn start_calendar_bridge(detector: Arc<MeetingDetector>) -> JoinHandle<()> {
                         start_epoch_ms: start.timestamp_millis(),
                         end_epoch_ms: end.timestamp_millis(),
                         attendees: item.attendees,
+                        meeting_url: item.meeting_url,
                     })
                 })
                 .collect();
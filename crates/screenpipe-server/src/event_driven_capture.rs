@@ -87,6 +87,14 @@ pub struct EventDrivenCaptureConfig {
     pub visual_check_interval_ms: u64,
     /// Frame difference threshold (0.0–1.0) above which a VisualChange trigger fires.
     pub visual_change_threshold: f64,
+    /// Caps the longest edge of captured screenshots, in pixels. Frames
+    /// wider or taller than this are downscaled (aspect ratio preserved)
+    /// before accessibility/OCR processing and storage. `None` disables
+    /// the cap. Set per-monitor via `MonitorCaptureSettings::max_dimension`.
+    pub max_capture_dimension: Option<u32>,
+    /// Whether to persist decoded `otpauth://` (2FA enrollment) QR payloads
+    /// found in captures. Other QR payloads are always persisted.
+    pub capture_otp_qr_codes: bool,
 }
 
 impl Default for EventDrivenCaptureConfig {
@@ -101,6 +109,8 @@ impl Default for EventDrivenCaptureConfig {
             capture_on_clipboard: true,
             visual_check_interval_ms: 3_000, // check every 3 seconds
             visual_change_threshold: 0.05,   // ~5% difference triggers capture
+            max_capture_dimension: None,
+            capture_otp_qr_codes: true,
         }
     }
 }
@@ -258,6 +268,8 @@ pub async fn event_driven_capture_loop(
             use_pii_removal,
             None, // first capture — no previous hash
             last_db_write,
+            state.config.max_capture_dimension,
+            state.config.capture_otp_qr_codes,
         )
         .await
         {
@@ -288,16 +300,66 @@ pub async fn event_driven_capture_loop(
         info!("screen is locked on startup, skipping initial capture for monitor {}", monitor_id);
     }
 
+    // Tracks whether we've already opened a capture_gaps row for the current
+    // lock span, so we don't insert a new row on every poll iteration.
+    let mut gap_open = false;
+    // Same tracking, but for a user-initiated privacy pause (tray/API) rather
+    // than the screen being locked — kept separate so the two reasons never
+    // get mixed up in the capture_gaps row that's currently open.
+    let mut pause_gap_open = false;
+
     loop {
         if stop_signal.load(Ordering::Relaxed) {
             info!("event-driven capture stopping for monitor {}", monitor_id);
             break;
         }
 
-        // Skip capture while the screen is locked / screensaver active
+        // Skip capture while the screen is locked / screensaver active.
+        // Record an explicit gap marker so the timeline can show "screen
+        // locked" instead of silently having no frames for that span.
         if crate::sleep_monitor::screen_is_locked() {
+            if !gap_open {
+                if let Err(e) = db
+                    .open_capture_gap(&device_name, "screen_locked", Utc::now())
+                    .await
+                {
+                    warn!("failed to open capture gap for monitor {}: {}", monitor_id, e);
+                }
+                gap_open = true;
+            }
             tokio::time::sleep(poll_interval).await;
             continue;
+        } else if gap_open {
+            if let Err(e) = db.close_open_capture_gap(&device_name, Utc::now()).await {
+                warn!("failed to close capture gap for monitor {}: {}", monitor_id, e);
+            }
+            gap_open = false;
+        }
+
+        // Skip capture while the user has paused screen capture from the
+        // tray/API. Same capture_gaps mechanism as screen-lock above, with a
+        // distinct reason so the timeline can tell the two apart.
+        if screenpipe_audio::privacy_pause::is_paused(screenpipe_audio::privacy_pause::CapturePipeline::Screen) {
+            if !pause_gap_open {
+                if let Err(e) = db
+                    .open_capture_gap(
+                        &device_name,
+                        screenpipe_audio::privacy_pause::PAUSE_GAP_REASON,
+                        Utc::now(),
+                    )
+                    .await
+                {
+                    warn!("failed to open capture gap for monitor {}: {}", monitor_id, e);
+                }
+                pause_gap_open = true;
+            }
+            tokio::time::sleep(poll_interval).await;
+            continue;
+        } else if pause_gap_open {
+            if let Err(e) = db.close_open_capture_gap(&device_name, Utc::now()).await {
+                warn!("failed to close capture gap for monitor {}: {}", monitor_id, e);
+            }
+            pause_gap_open = false;
         }
 
         // Check for external triggers (non-blocking)
@@ -382,6 +444,8 @@ pub async fn event_driven_capture_loop(
                         use_pii_removal,
                         last_content_hash,
                         last_db_write,
+                        state.config.max_capture_dimension,
+                        state.config.capture_otp_qr_codes,
                     ),
                 )
                 .await;
@@ -515,16 +579,32 @@ async fn do_capture(
     use_pii_removal: bool,
     previous_content_hash: Option<i64>,
     last_db_write: Instant,
+    max_capture_dimension: Option<u32>,
+    capture_otp_qr_codes: bool,
 ) -> Result<CaptureOutput> {
     let captured_at = Utc::now();
 
     // Take screenshot
-    let (image, capture_dur) = capture_monitor_image(monitor).await?;
+    let (mut image, capture_dur) = capture_monitor_image(monitor).await?;
     debug!(
         "screenshot captured in {:?} for monitor {}",
         capture_dur, monitor_id
     );
 
+    // Apply the per-monitor resolution cap, if configured. Preserves aspect
+    // ratio and never upscales — mirrors `screenpipe_vision::write_tier`'s
+    // downscale-ratio logic.
+    if let Some(max_dim) = max_capture_dimension {
+        let (width, height) = (image.width(), image.height());
+        let longest_edge = width.max(height);
+        if longest_edge > max_dim {
+            let ratio = max_dim as f64 / longest_edge as f64;
+            let new_width = ((width as f64) * ratio).round().max(1.0) as u32;
+            let new_height = ((height as f64) * ratio).round().max(1.0) as u32;
+            image = image.resize(new_width, new_height, image::imageops::FilterType::Triangle);
+        }
+    }
+
     // Walk accessibility tree on blocking thread (AX APIs are synchronous)
     let config = tree_walker_config.clone();
     let tree_snapshot = tokio::task::spawn_blocking(move || {
@@ -618,6 +698,7 @@ async fn do_capture(
         focused: true, // event-driven captures are always for the focused window
         capture_trigger: trigger.as_str(),
         use_pii_removal,
+        capture_otp_qr_codes,
     };
 
     let result = paired_capture(&ctx, tree_snapshot.as_ref()).await?;
@@ -9,6 +9,8 @@
 //! This eliminates the heavy `find_video_chunks` polling that starved the DB pool.
 
 use chrono::{DateTime, Datelike, Utc};
+use oasgen::OaSchema;
+use serde::Serialize;
 use std::collections::BTreeMap;
 use tokio::sync::{broadcast, RwLock};
 use tracing::{info, warn};
@@ -48,6 +50,86 @@ pub struct HotAudio {
     pub speaker_name: Option<String>,
 }
 
+/// Cached UI event (click, keystroke summary, app/window switch, ...) from
+/// the accessibility capture pipeline — see `crate::ui_recorder`. Only
+/// pushed when the recorder is configured to actually record input events
+/// (`UiRecorderConfig::record_input_events`), so the hot cache never holds
+/// more than what's also being persisted to the DB.
+#[derive(Debug, Clone)]
+pub struct HotUiEvent {
+    pub timestamp: DateTime<Utc>,
+    pub event_type: String,
+    pub app_name: Option<String>,
+    pub window_title: Option<String>,
+    pub text_content: Option<String>,
+}
+
+/// One match from [`HotFrameCache::instant_search`] — an OCR'd frame, a
+/// transcribed audio chunk, or a UI event. Deliberately smaller than
+/// `ContentItem` (no tags, no speaker lookup): the overlay just needs enough
+/// to render a result row and jump to the frame/chunk on click.
+#[derive(Debug, Clone, Serialize, OaSchema)]
+pub struct InstantSearchHit {
+    pub kind: InstantSearchKind,
+    pub timestamp: DateTime<Utc>,
+    pub app_name: Option<String>,
+    pub window_name: Option<String>,
+    pub device_name: String,
+    pub snippet: String,
+    pub frame_id: Option<i64>,
+    pub audio_chunk_id: Option<i64>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, OaSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum InstantSearchKind {
+    Frame,
+    Audio,
+    UiEvent,
+}
+
+/// Snapshot of everything the hot cache holds for a trailing time window,
+/// for [`crate::routes::search::realtime_context_handler`] — assembling
+/// context for an AI agent that needs "what has the user been doing for the
+/// last few minutes" without a DB round-trip or reimplementing the
+/// frame/audio/UI-event join logic that already lives on this cache.
+#[derive(Debug, Clone, Serialize, OaSchema)]
+pub struct RealtimeContext {
+    pub frames: Vec<RealtimeFrame>,
+    pub audio: Vec<RealtimeAudio>,
+    pub ui_events: Vec<RealtimeUiEvent>,
+}
+
+#[derive(Debug, Clone, Serialize, OaSchema)]
+pub struct RealtimeFrame {
+    pub frame_id: i64,
+    pub timestamp: DateTime<Utc>,
+    pub device_name: String,
+    pub app_name: String,
+    pub window_name: String,
+    pub ocr_text_preview: String,
+    pub browser_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, OaSchema)]
+pub struct RealtimeAudio {
+    pub audio_chunk_id: i64,
+    pub timestamp: DateTime<Utc>,
+    pub transcription: String,
+    pub device_name: String,
+    pub is_input: bool,
+    pub speaker_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, OaSchema)]
+pub struct RealtimeUiEvent {
+    pub timestamp: DateTime<Utc>,
+    pub event_type: String,
+    pub app_name: Option<String>,
+    pub window_title: Option<String>,
+    pub text_content: Option<String>,
+}
+
 /// In-memory cache for today's frames and audio.
 ///
 /// Uses BTreeMap for O(log n) insert and efficient time-range queries.
@@ -55,8 +137,10 @@ pub struct HotAudio {
 pub struct HotFrameCache {
     frames: RwLock<BTreeMap<(DateTime<Utc>, i64), HotFrame>>,
     audio: RwLock<BTreeMap<DateTime<Utc>, Vec<HotAudio>>>,
+    ui_events: RwLock<BTreeMap<DateTime<Utc>, Vec<HotUiEvent>>>,
     frame_notify: broadcast::Sender<HotFrame>,
     audio_notify: broadcast::Sender<HotAudio>,
+    ui_event_notify: broadcast::Sender<HotUiEvent>,
     /// Ordinal day number — cache is cleared on day rollover.
     cache_day: RwLock<u32>,
 }
@@ -66,11 +150,14 @@ impl HotFrameCache {
     pub fn new() -> Self {
         let (frame_tx, _) = broadcast::channel(256);
         let (audio_tx, _) = broadcast::channel(256);
+        let (ui_event_tx, _) = broadcast::channel(256);
         Self {
             frames: RwLock::new(BTreeMap::new()),
             audio: RwLock::new(BTreeMap::new()),
+            ui_events: RwLock::new(BTreeMap::new()),
             frame_notify: frame_tx,
             audio_notify: audio_tx,
+            ui_event_notify: ui_event_tx,
             cache_day: RwLock::new(Utc::now().ordinal()),
         }
     }
@@ -86,6 +173,7 @@ impl HotFrameCache {
             );
             self.frames.write().await.clear();
             self.audio.write().await.clear();
+            self.ui_events.write().await.clear();
             *day = today;
         }
     }
@@ -111,6 +199,18 @@ impl HotFrameCache {
         let _ = self.audio_notify.send(audio);
     }
 
+    /// Push a captured UI event into the cache and broadcast to subscribers.
+    pub async fn push_ui_event(&self, event: HotUiEvent) {
+        self.maybe_rollover().await;
+        self.ui_events
+            .write()
+            .await
+            .entry(event.timestamp)
+            .or_default()
+            .push(event.clone());
+        let _ = self.ui_event_notify.send(event);
+    }
+
     /// Subscribe to live frame updates (for WS handlers).
     pub fn subscribe_frames(&self) -> broadcast::Receiver<HotFrame> {
         self.frame_notify.subscribe()
@@ -121,6 +221,11 @@ impl HotFrameCache {
         self.audio_notify.subscribe()
     }
 
+    /// Subscribe to live UI event updates (for WS handlers).
+    pub fn subscribe_ui_events(&self) -> broadcast::Receiver<HotUiEvent> {
+        self.ui_event_notify.subscribe()
+    }
+
     /// Read frames from cache for a time range, with associated audio.
     /// Returns `TimeSeriesFrame` entries compatible with the existing WS format.
     pub async fn get_frames_in_range(
@@ -149,6 +254,164 @@ impl HotFrameCache {
         ts.ordinal() == *day && ts.year() == Utc::now().year()
     }
 
+    /// Scan the cache for `query` (case-insensitive substring match against
+    /// OCR text / app / window name for frames, transcription for audio).
+    /// Pure in-memory, most-recent-first — no DB round-trip — so it's fast
+    /// enough to back a search overlay that filters as the user types.
+    pub async fn instant_search(&self, query: &str, limit: usize) -> Vec<InstantSearchHit> {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() || limit == 0 {
+            return Vec::new();
+        }
+
+        let mut hits = Vec::new();
+
+        {
+            let frames = self.frames.read().await;
+            for hot in frames.values().rev() {
+                if hits.len() >= limit {
+                    break;
+                }
+                if hot.ocr_text_preview.to_lowercase().contains(&query)
+                    || hot.app_name.to_lowercase().contains(&query)
+                    || hot.window_name.to_lowercase().contains(&query)
+                {
+                    hits.push(InstantSearchHit {
+                        kind: InstantSearchKind::Frame,
+                        timestamp: hot.timestamp,
+                        app_name: Some(hot.app_name.clone()),
+                        window_name: Some(hot.window_name.clone()),
+                        device_name: hot.device_name.clone(),
+                        snippet: hot.ocr_text_preview.clone(),
+                        frame_id: Some(hot.frame_id),
+                        audio_chunk_id: None,
+                    });
+                }
+            }
+        }
+
+        if hits.len() < limit {
+            let audio = self.audio.read().await;
+            'outer: for entries in audio.values().rev() {
+                for a in entries.iter().rev() {
+                    if hits.len() >= limit {
+                        break 'outer;
+                    }
+                    if a.transcription.to_lowercase().contains(&query) {
+                        hits.push(InstantSearchHit {
+                            kind: InstantSearchKind::Audio,
+                            timestamp: a.timestamp,
+                            app_name: None,
+                            window_name: None,
+                            device_name: a.device_name.clone(),
+                            snippet: a.transcription.clone(),
+                            frame_id: None,
+                            audio_chunk_id: Some(a.audio_chunk_id),
+                        });
+                    }
+                }
+            }
+        }
+
+        if hits.len() < limit {
+            let ui_events = self.ui_events.read().await;
+            'outer_ui: for entries in ui_events.values().rev() {
+                for e in entries.iter().rev() {
+                    if hits.len() >= limit {
+                        break 'outer_ui;
+                    }
+                    let Some(text) = e.text_content.as_deref() else {
+                        continue;
+                    };
+                    if text.to_lowercase().contains(&query) {
+                        hits.push(InstantSearchHit {
+                            kind: InstantSearchKind::UiEvent,
+                            timestamp: e.timestamp,
+                            app_name: e.app_name.clone(),
+                            window_name: e.window_title.clone(),
+                            device_name: "ui_events".to_string(),
+                            snippet: text.to_string(),
+                            frame_id: None,
+                            audio_chunk_id: None,
+                        });
+                    }
+                }
+            }
+        }
+
+        hits.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+        hits.truncate(limit);
+        hits
+    }
+
+    /// Snapshot of frames, audio, and UI events from the last `minutes`
+    /// minutes, most-recent-first — see [`RealtimeContext`].
+    pub async fn recent_context(&self, minutes: i64) -> RealtimeContext {
+        let end = Utc::now();
+        let start = end - chrono::Duration::minutes(minutes.max(0));
+
+        let frames = {
+            let frames = self.frames.read().await;
+            let range_start = (start, i64::MIN);
+            let range_end = (end, i64::MAX);
+            let mut out: Vec<RealtimeFrame> = frames
+                .range(range_start..=range_end)
+                .map(|(_, hot)| RealtimeFrame {
+                    frame_id: hot.frame_id,
+                    timestamp: hot.timestamp,
+                    device_name: hot.device_name.clone(),
+                    app_name: hot.app_name.clone(),
+                    window_name: hot.window_name.clone(),
+                    ocr_text_preview: hot.ocr_text_preview.clone(),
+                    browser_url: hot.browser_url.clone(),
+                })
+                .collect();
+            out.reverse();
+            out
+        };
+
+        let audio = {
+            let audio = self.audio.read().await;
+            let mut out: Vec<RealtimeAudio> = audio
+                .range(start..=end)
+                .flat_map(|(_, entries)| entries.iter())
+                .map(|a| RealtimeAudio {
+                    audio_chunk_id: a.audio_chunk_id,
+                    timestamp: a.timestamp,
+                    transcription: a.transcription.clone(),
+                    device_name: a.device_name.clone(),
+                    is_input: a.is_input,
+                    speaker_name: a.speaker_name.clone(),
+                })
+                .collect();
+            out.reverse();
+            out
+        };
+
+        let ui_events = {
+            let ui_events = self.ui_events.read().await;
+            let mut out: Vec<RealtimeUiEvent> = ui_events
+                .range(start..=end)
+                .flat_map(|(_, entries)| entries.iter())
+                .map(|e| RealtimeUiEvent {
+                    timestamp: e.timestamp,
+                    event_type: e.event_type.clone(),
+                    app_name: e.app_name.clone(),
+                    window_title: e.window_title.clone(),
+                    text_content: e.text_content.clone(),
+                })
+                .collect();
+            out.reverse();
+            out
+        };
+
+        RealtimeContext {
+            frames,
+            audio,
+            ui_events,
+        }
+    }
+
     /// Warm the cache from DB on cold start (load last N hours).
     pub async fn warm_from_db(&self, db: &screenpipe_db::DatabaseManager, hours: i64) {
         let end = Utc::now();
@@ -396,6 +659,55 @@ mod tests {
         assert_eq!(received.frame_id, 42);
     }
 
+    #[tokio::test]
+    async fn test_instant_search_matches_frame_and_audio() {
+        let cache = HotFrameCache::new();
+        let now = Utc::now();
+
+        cache
+            .push_frame(HotFrame {
+                frame_id: 1,
+                timestamp: now,
+                device_name: "monitor_0".to_string(),
+                app_name: "Terminal".to_string(),
+                window_name: "zsh".to_string(),
+                ocr_text_preview: "cargo build --workspace".to_string(),
+                snapshot_path: "/tmp/test.jpg".to_string(),
+                browser_url: None,
+                capture_trigger: "click".to_string(),
+                offset_index: 0,
+                fps: 0.033,
+            })
+            .await;
+
+        cache
+            .push_audio(HotAudio {
+                audio_chunk_id: 7,
+                timestamp: now,
+                transcription: "let's ship the search overlay today".to_string(),
+                device_name: "mic_0".to_string(),
+                is_input: true,
+                audio_file_path: "/tmp/audio.mp4".to_string(),
+                duration_secs: 3.0,
+                start_time: None,
+                end_time: None,
+                speaker_id: None,
+                speaker_name: None,
+            })
+            .await;
+
+        let frame_hits = cache.instant_search("cargo", 10).await;
+        assert_eq!(frame_hits.len(), 1);
+        assert!(matches!(frame_hits[0].kind, InstantSearchKind::Frame));
+
+        let audio_hits = cache.instant_search("overlay", 10).await;
+        assert_eq!(audio_hits.len(), 1);
+        assert!(matches!(audio_hits[0].kind, InstantSearchKind::Audio));
+
+        assert!(cache.instant_search("", 10).await.is_empty());
+        assert!(cache.instant_search("nonexistent-term", 10).await.is_empty());
+    }
+
     #[tokio::test]
     async fn test_is_today() {
         let cache = HotFrameCache::new();
@@ -6,7 +6,14 @@
 //!
 //! Polls the detector every 5 seconds and writes INSERT/UPDATE rows to the
 //! `meetings` table. On startup, closes any orphaned meetings (crash recovery).
+//!
+//! When a meeting ends, its transcript is also run through
+//! [`crate::action_item_extraction`] as a best-effort post-processing step
+//! (mirrors how `paired_capture` treats thumbnail generation): a failure
+//! there must not affect the already-recorded meeting.
 
+use crate::action_item_extraction::extract_action_items;
+use chrono::{DateTime, Utc};
 use screenpipe_audio::meeting_detector::MeetingDetector;
 use screenpipe_db::DatabaseManager;
 use std::sync::Arc;
@@ -83,6 +90,7 @@ pub fn start_meeting_persister(
                         match db.end_meeting(id, &now).await {
                             Ok(()) => {
                                 info!("meeting persister: meeting ended (id={})", id);
+                                extract_action_items_for_meeting(&db, id).await;
                             }
                             Err(e) => {
                                 error!(
@@ -106,3 +114,81 @@ pub fn start_meeting_persister(
         }
     })
 }
+
+/// Extract action items from a just-ended meeting's transcript and store
+/// them as tasks, emitting `task_created` for each. Best-effort: any
+/// failure here is logged and swallowed.
+async fn extract_action_items_for_meeting(db: &DatabaseManager, meeting_id: i64) {
+    let meeting = match db.get_meeting_by_id(meeting_id).await {
+        Ok(meeting) => meeting,
+        Err(e) => {
+            warn!(
+                "meeting persister: failed to load meeting {} for action-item extraction: {}",
+                meeting_id, e
+            );
+            return;
+        }
+    };
+
+    let (Some(start), Some(end)) = (
+        DateTime::parse_from_rfc3339(&meeting.meeting_start).ok(),
+        meeting
+            .meeting_end
+            .as_deref()
+            .and_then(|s| DateTime::parse_from_rfc3339(s).ok()),
+    ) else {
+        return;
+    };
+
+    let transcript_lines = match db
+        .get_transcript_for_range(start.with_timezone(&Utc), end.with_timezone(&Utc))
+        .await
+    {
+        Ok(lines) => lines,
+        Err(e) => {
+            warn!(
+                "meeting persister: failed to load transcript for meeting {}: {}",
+                meeting_id, e
+            );
+            return;
+        }
+    };
+
+    if transcript_lines.is_empty() {
+        return;
+    }
+
+    let transcript = transcript_lines
+        .into_iter()
+        .map(|(_, _, text)| text)
+        .collect::<Vec<_>>()
+        .join(". ");
+
+    for item in extract_action_items(&transcript, Utc::now().date_naive()) {
+        match db
+            .insert_task(
+                &item.description,
+                Some(meeting_id),
+                Some(&item.source_snippet),
+                item.due_date.as_deref(),
+            )
+            .await
+        {
+            Ok(task) => {
+                let _ = screenpipe_events::send_event(
+                    "task_created",
+                    serde_json::json!({
+                        "task_id": task.id,
+                        "meeting_id": meeting_id,
+                        "description": task.description,
+                        "due_date": task.due_date,
+                    }),
+                );
+            }
+            Err(e) => warn!(
+                "meeting persister: failed to store action item for meeting {}: {}",
+                meeting_id, e
+            ),
+        }
+    }
+}
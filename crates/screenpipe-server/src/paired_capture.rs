@@ -11,13 +11,15 @@
 //! 3. Writes the JPEG snapshot to disk
 //! 4. Inserts a frame with accessibility text + snapshot path into the DB
 
+use crate::entity_extraction::extract_entities;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use image::DynamicImage;
 use screenpipe_accessibility::tree::{create_tree_walker, TreeSnapshot, TreeWalkerConfig};
 use screenpipe_core::pii_removal::remove_pii;
-use screenpipe_db::DatabaseManager;
+use screenpipe_db::{DatabaseManager, FrameAssetTier};
 use screenpipe_vision::snapshot_writer::SnapshotWriter;
+use screenpipe_vision::{detect_codes, write_tier};
 use std::sync::Arc;
 use std::time::Instant;
 use tracing::{debug, warn};
@@ -36,8 +38,17 @@ pub struct CaptureContext<'a> {
     pub focused: bool,
     pub capture_trigger: &'a str,
     pub use_pii_removal: bool,
+    /// Whether to persist decoded `otpauth://` (2FA enrollment) QR payloads.
+    /// Other QR payloads (WiFi, tickets, URLs, ...) are always persisted —
+    /// this only opts out of storing TOTP secrets, which are sensitive
+    /// credentials rather than recallable information.
+    pub capture_otp_qr_codes: bool,
 }
 
+/// JPEG quality used for generated thumbnail/medium tiers. Lower than the
+/// full-resolution snapshot's quality since these are only ever shown small.
+const THUMBNAIL_QUALITY: u8 = 70;
+
 /// Result of a paired capture operation.
 #[derive(Debug)]
 pub struct PairedCaptureResult {
@@ -224,6 +235,79 @@ pub async fn paired_capture(
         )
         .await?;
 
+    // Generate the thumbnail tier for timeline scrubbing. Best-effort: a
+    // failure here must not fail the capture, since the full-resolution
+    // frame is already safely persisted.
+    match write_tier(&ctx.image, &snapshot_path, FrameAssetTier::Thumbnail, THUMBNAIL_QUALITY) {
+        Ok((path, width, height)) => {
+            if let Err(e) = ctx
+                .db
+                .insert_frame_asset(
+                    frame_id,
+                    FrameAssetTier::Thumbnail,
+                    &path.to_string_lossy(),
+                    width as i64,
+                    height as i64,
+                )
+                .await
+            {
+                warn!("failed to record thumbnail asset for frame {}: {}", frame_id, e);
+            }
+        }
+        Err(e) => warn!("failed to generate thumbnail for frame {}: {}", frame_id, e),
+    }
+
+    // Broadcast so /ws/events and /sse/events subscribers (e.g. computer-use
+    // agents, activity_classifier) learn about new frames without polling
+    // the search endpoint.
+    let _ = screenpipe_events::send_event(
+        "new_frame",
+        serde_json::json!({
+            "frame_id": frame_id,
+            "device_name": ctx.device_name,
+            "app_name": ctx.app_name,
+            "window_name": ctx.window_name,
+            "browser_url": ctx.browser_url,
+            "timestamp": ctx.captured_at,
+        }),
+    );
+
+    // Scan for QR codes (WiFi credentials, ticket/boarding-pass QR codes,
+    // TOTP/2FA enrollment URIs). Best-effort, like the thumbnail above: a
+    // scan failure must not fail the already-persisted capture.
+    for code in detect_codes(&ctx.image) {
+        if code.is_otp_enrollment() && !ctx.capture_otp_qr_codes {
+            debug!("skipping otpauth QR payload for frame {} (capture_otp_qr_codes disabled)", frame_id);
+            continue;
+        }
+        if let Err(e) = ctx.db.insert_frame_code(frame_id, code.kind, &code.payload).await {
+            warn!("failed to record {} code for frame {}: {}", code.kind, frame_id, e);
+        }
+    }
+
+    // Extract people/organizations/projects from the sanitized text for
+    // entity pages and search filters. Best-effort, like the thumbnail and
+    // QR scan above: a failure here must not fail the already-persisted
+    // capture.
+    if let Some(text) = sanitized_text.as_deref() {
+        for entity in extract_entities(text) {
+            if let Err(e) = ctx
+                .db
+                .insert_entity(
+                    &entity.name,
+                    entity.entity_type,
+                    Some(frame_id),
+                    None,
+                    ctx.captured_at,
+                    entity.confidence,
+                )
+                .await
+            {
+                warn!("failed to record entity {:?} for frame {}: {}", entity.name, frame_id, e);
+            }
+        }
+    }
+
     let duration_ms = start.elapsed().as_millis() as u64;
     debug!(
         "paired_capture: frame_id={}, trigger={}, text_source={:?}, total={duration_ms}ms",
@@ -319,6 +403,7 @@ mod tests {
             focused: true,
             capture_trigger: "click",
             use_pii_removal: false,
+            capture_otp_qr_codes: true,
         };
 
         let result = paired_capture(&ctx, None).await.unwrap();
@@ -352,6 +437,7 @@ mod tests {
             focused: true,
             capture_trigger: "app_switch",
             use_pii_removal: false,
+            capture_otp_qr_codes: true,
         };
 
         let snap = TreeSnapshot {
@@ -407,6 +493,7 @@ mod tests {
             focused: true,
             capture_trigger: "idle",
             use_pii_removal: false,
+            capture_otp_qr_codes: true,
         };
 
         // Empty accessibility text should be treated as no text
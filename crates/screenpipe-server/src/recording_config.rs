@@ -7,6 +7,7 @@ use screenpipe_audio::audio_manager::AudioManagerBuilder;
 use screenpipe_audio::core::engine::AudioTranscriptionEngine;
 use screenpipe_audio::transcription::VocabularyEntry;
 use screenpipe_audio::vad::VadEngineEnum;
+use screenpipe_core::text_filters::TranscriptFilterConfig;
 use screenpipe_core::Language;
 use screenpipe_vision::PipelineMetrics;
 use std::path::PathBuf;
@@ -22,14 +23,22 @@ pub struct RecordingConfig {
     // Core recording
     pub audio_chunk_duration: u64, // seconds
     pub port: u16,
+    /// Port for the gRPC control-plane API. Disabled (`None`) unless set.
+    pub grpc_port: Option<u16>,
     pub data_dir: PathBuf,
 
     // Feature toggles
     pub disable_audio: bool,
     pub disable_vision: bool,
     pub use_pii_removal: bool,
+    pub mask_profanity: bool,
+    pub strip_filler_words: bool,
     pub enable_input_capture: bool,
     pub enable_accessibility: bool,
+    /// Persist decoded `otpauth://` (2FA enrollment) QR payloads found in
+    /// captures. Other decoded QR payloads (WiFi, tickets, URLs) are always
+    /// persisted regardless of this flag.
+    pub capture_otp_qr_codes: bool,
 
     // Engines (typed, not strings)
     pub audio_transcription_engine: AudioTranscriptionEngine,
@@ -37,6 +46,14 @@ pub struct RecordingConfig {
 
     // Devices & monitors
     pub audio_devices: Vec<String>,
+    /// Devices (by name, subset of `audio_devices`) with noise suppression
+    /// enabled before VAD/transcription. See `screenpipe_audio::denoise`.
+    pub denoise_devices: Vec<String>,
+    /// Devices (by name, subset of `audio_devices`) recorded as two
+    /// per-channel virtual devices (left/right) instead of one
+    /// downmixed-to-mono stream. See
+    /// `screenpipe_audio::core::device::ChannelSelector`.
+    pub channel_split_devices: Vec<String>,
     pub use_system_default_audio: bool,
     pub monitor_ids: Vec<String>,
     pub use_all_monitors: bool,
@@ -98,10 +115,16 @@ impl RecordingConfig {
             .languages(self.languages.clone())
             .transcription_engine(self.audio_transcription_engine.clone())
             .enabled_devices(audio_devices)
+            .denoise_devices(self.denoise_devices.clone())
+            .channel_split_devices(self.channel_split_devices.clone())
             .use_system_default_audio(self.use_system_default_audio)
             .deepgram_api_key(self.deepgram_api_key.clone())
             .output_path(output_path)
             .use_pii_removal(self.use_pii_removal)
+            .transcript_filter(TranscriptFilterConfig {
+                mask_profanity: self.mask_profanity,
+                strip_filler_words: self.strip_filler_words,
+            })
             .transcription_mode(self.transcription_mode.clone())
             .vocabulary(self.vocabulary.clone())
     }
@@ -118,6 +141,7 @@ impl RecordingConfig {
             included_windows: self.included_windows.clone(),
             vision_metrics,
             use_pii_removal: self.use_pii_removal,
+            capture_otp_qr_codes: self.capture_otp_qr_codes,
         }
     }
 }
@@ -11,13 +11,13 @@ use oasgen::{oasgen, OaSchema};
 
 use chrono::{DateTime, Utc};
 use screenpipe_audio::core::device::DeviceType;
-use screenpipe_db::{Speaker, TagContentType};
+use screenpipe_db::{Bookmark, Speaker, TagContentType};
 
 use image::ImageFormat;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use std::sync::Arc;
-use tracing::error;
+use tracing::{error, warn};
 
 use crate::{
     server::AppState,
@@ -70,6 +70,19 @@ pub struct AudioContent {
     pub speaker: Option<Speaker>,
     pub start_time: Option<f64>,
     pub end_time: Option<f64>,
+    /// Extractive summary of `transcription` for long recordings — see
+    /// `screenpipe_db::text_summarization`. `None` when the transcription is
+    /// already short enough to use as its own snippet.
+    pub summary: Option<String>,
+    /// Best-effort attribution to the app that was focused when this
+    /// (output-device) transcript was captured — see
+    /// `screenpipe_db::AudioResult::source_app`. Always `None` for
+    /// input-device transcripts.
+    pub source_app: Option<String>,
+    /// Average per-token probability from Whisper, or `None` for engines
+    /// that don't report it (Deepgram, Qwen3-ASR) or transcripts predating
+    /// this field — see `screenpipe_db::AudioResult::confidence`.
+    pub confidence: Option<f64>,
 }
 
 #[derive(OaSchema, Serialize, Deserialize, Debug, Clone)]
@@ -237,6 +250,200 @@ pub(crate) async fn get_tags_batch(
     }
 }
 
+#[derive(OaSchema, Serialize)]
+pub struct ListByTagResponse {
+    ids: Vec<i64>,
+}
+
+/// List the ids of frames or audio chunks carrying a tag.
+/// GET /tags/:content_type/:tag_name/ids
+#[oasgen]
+pub(crate) async fn list_content_ids_by_tag(
+    State(state): State<Arc<AppState>>,
+    Path((content_type, tag_name)): Path<(String, String)>,
+) -> Result<Json<ListByTagResponse>, (StatusCode, JsonResponse<Value>)> {
+    let content_type = match content_type.as_str() {
+        "vision" => TagContentType::Vision,
+        "audio" => TagContentType::Audio,
+        _ => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                JsonResponse(json!({"error": "Invalid content type"})),
+            ))
+        }
+    };
+
+    match state
+        .db
+        .list_content_ids_by_tag(&tag_name, content_type)
+        .await
+    {
+        Ok(ids) => Ok(JsonResponse(ListByTagResponse { ids })),
+        Err(e) => {
+            error!("Failed to list content by tag: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                JsonResponse(json!({"error": e.to_string()})),
+            ))
+        }
+    }
+}
+
+#[derive(OaSchema, Deserialize)]
+pub struct TagTimeRangeRequest {
+    start_time: DateTime<Utc>,
+    end_time: DateTime<Utc>,
+    tags: Vec<String>,
+}
+
+#[derive(OaSchema, Serialize)]
+pub struct TagTimeRangeResponse {
+    tagged_ids: Vec<i64>,
+}
+
+/// Tag every frame or audio chunk in a time range at once.
+/// POST /tags/:content_type/range  { "start_time", "end_time", "tags": [...] }
+#[oasgen]
+pub(crate) async fn tag_time_range(
+    State(state): State<Arc<AppState>>,
+    Path(content_type): Path<String>,
+    JsonResponse(payload): JsonResponse<TagTimeRangeRequest>,
+) -> Result<Json<TagTimeRangeResponse>, (StatusCode, JsonResponse<Value>)> {
+    let content_type = match content_type.as_str() {
+        "vision" => TagContentType::Vision,
+        "audio" => TagContentType::Audio,
+        _ => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                JsonResponse(json!({"error": "Invalid content type"})),
+            ))
+        }
+    };
+
+    match state
+        .db
+        .tag_time_range(payload.start_time, payload.end_time, content_type, payload.tags)
+        .await
+    {
+        Ok(tagged_ids) => Ok(JsonResponse(TagTimeRangeResponse { tagged_ids })),
+        Err(e) => {
+            error!("Failed to tag time range: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                JsonResponse(json!({"error": e.to_string()})),
+            ))
+        }
+    }
+}
+
+#[derive(OaSchema, Deserialize)]
+pub struct AddBookmarkRequest {
+    content_type: String,
+    content_id: i64,
+    label: Option<String>,
+}
+
+#[derive(OaSchema, Serialize)]
+pub struct AddBookmarkResponse {
+    id: i64,
+}
+
+/// POST /bookmarks  { "content_type": "vision", "content_id": 42, "label": "standup" }
+#[oasgen]
+pub(crate) async fn add_bookmark(
+    State(state): State<Arc<AppState>>,
+    JsonResponse(payload): JsonResponse<AddBookmarkRequest>,
+) -> Result<Json<AddBookmarkResponse>, (StatusCode, JsonResponse<Value>)> {
+    let content_type = match payload.content_type.as_str() {
+        "vision" => TagContentType::Vision,
+        "audio" => TagContentType::Audio,
+        _ => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                JsonResponse(json!({"error": "Invalid content type"})),
+            ))
+        }
+    };
+
+    match state
+        .db
+        .add_bookmark(content_type, payload.content_id, payload.label)
+        .await
+    {
+        Ok(id) => Ok(JsonResponse(AddBookmarkResponse { id })),
+        Err(e) => {
+            error!("Failed to add bookmark: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                JsonResponse(json!({"error": e.to_string()})),
+            ))
+        }
+    }
+}
+
+#[derive(OaSchema, Serialize)]
+pub struct RemoveBookmarkResponse {
+    success: bool,
+}
+
+/// DELETE /bookmarks/:id
+#[oasgen]
+pub(crate) async fn remove_bookmark(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<i64>,
+) -> Result<Json<RemoveBookmarkResponse>, (StatusCode, JsonResponse<Value>)> {
+    match state.db.remove_bookmark(id).await {
+        Ok(_) => Ok(JsonResponse(RemoveBookmarkResponse { success: true })),
+        Err(e) => {
+            error!("Failed to remove bookmark: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                JsonResponse(json!({"error": e.to_string()})),
+            ))
+        }
+    }
+}
+
+#[derive(OaSchema, Deserialize)]
+pub struct ListBookmarksQuery {
+    content_type: Option<String>,
+}
+
+#[derive(OaSchema, Serialize)]
+pub struct ListBookmarksResponse {
+    bookmarks: Vec<Bookmark>,
+}
+
+/// GET /bookmarks?content_type=vision
+#[oasgen]
+pub(crate) async fn list_bookmarks(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<ListBookmarksQuery>,
+) -> Result<Json<ListBookmarksResponse>, (StatusCode, JsonResponse<Value>)> {
+    let content_type = match params.content_type.as_deref() {
+        Some("vision") => Some(TagContentType::Vision),
+        Some("audio") => Some(TagContentType::Audio),
+        Some(_) => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                JsonResponse(json!({"error": "Invalid content type"})),
+            ))
+        }
+        None => None,
+    };
+
+    match state.db.list_bookmarks(content_type).await {
+        Ok(bookmarks) => Ok(JsonResponse(ListBookmarksResponse { bookmarks })),
+        Err(e) => {
+            error!("Failed to list bookmarks: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                JsonResponse(json!({"error": e.to_string()})),
+            ))
+        }
+    }
+}
+
 #[derive(OaSchema, Deserialize)]
 pub struct AddContentRequest {
     pub device_name: String,     // Moved device_name to the top level
@@ -389,6 +596,28 @@ pub(crate) async fn add_transcription_to_db(
     )
     .await?;
 
+    // Extract people/organizations/projects from the transcript for entity
+    // pages and search filters. Best-effort: an extraction failure must not
+    // fail the already-persisted transcription.
+    for entity in crate::entity_extraction::extract_entities(&sanitized_transcription) {
+        if let Err(e) = db
+            .insert_entity(
+                &entity.name,
+                entity.entity_type,
+                None,
+                Some(dummy_audio_chunk_id),
+                Utc::now(),
+                entity.confidence,
+            )
+            .await
+        {
+            warn!(
+                "failed to record entity {:?} for audio chunk {}: {}",
+                entity.name, dummy_audio_chunk_id, e
+            );
+        }
+    }
+
     Ok(())
 }
 
@@ -12,7 +12,7 @@ use oasgen::{oasgen, OaSchema};
 
 use chrono::{DateTime, Utc};
 use screenpipe_core::pii_removal::detect_pii_regions;
-use screenpipe_db::TextPosition;
+use screenpipe_db::{FrameAssetTier, TextPosition};
 
 use image::GenericImageView;
 use serde::{Deserialize, Serialize};
@@ -39,6 +39,10 @@ pub struct GetFrameQuery {
     /// If true, blur/redact any detected PII (credit cards, SSNs, emails) in the frame
     #[serde(default)]
     pub redact_pii: bool,
+    /// Requested image tier for timeline scrubbing: "thumbnail" or "medium".
+    /// Falls back to the full-resolution frame if unset or unrecognized.
+    #[serde(default)]
+    pub tier: Option<String>,
 }
 
 #[oasgen]
@@ -50,8 +54,8 @@ pub async fn get_frame_data(
     let start_time = Instant::now();
 
     match timeout(Duration::from_secs(5), async {
-        // Skip cache if redact_pii is requested (need fresh processing)
-        if !query.redact_pii {
+        // Skip cache if redact_pii or a specific tier is requested (need fresh processing)
+        if !query.redact_pii && query.tier.is_none() {
             // Try to get frame from cache if enabled
             if let Some(cache) = &state.frame_image_cache {
                 let cache_result = cache.try_lock();
@@ -87,6 +91,9 @@ pub async fn get_frame_data(
                     if query.redact_pii {
                         return apply_pii_redaction(&state, frame_id, &file_path).await;
                     }
+                    if let Some(tier) = query.tier.as_deref() {
+                        return serve_frame_tier(&state, frame_id, &file_path, tier).await;
+                    }
                     // Cache snapshot path too
                     if let Some(cache) = &state.frame_image_cache {
                         if let Ok(mut cache) = cache.try_lock() {
@@ -206,7 +213,8 @@ async fn try_extract_and_serve_frame(
         .map_err(|_| anyhow::anyhow!("frame extraction semaphore closed"))?;
 
     let jpeg_q = crate::video::video_quality_to_jpeg_q(&state.video_quality);
-    let frame_path = extract_frame_from_video(file_path, offset_index, jpeg_q).await?;
+    let local_path = state.cold_storage.resolve_local_path(file_path).await?;
+    let frame_path = extract_frame_from_video(&local_path, offset_index, jpeg_q).await?;
 
     if redact_pii {
         return apply_pii_redaction(state, frame_id, &frame_path)
@@ -584,6 +592,110 @@ pub async fn get_frame_context(
     }))
 }
 
+/// An interactable UI element grounded to a frame, for computer-use agents
+/// replaying/planning actions against historical screens.
+#[derive(OaSchema, Serialize, Clone)]
+pub struct InteractableElement {
+    /// Coarse action-relevant kind: "button", "link", "entry", or "other".
+    pub kind: &'static str,
+    /// Original accessibility role (e.g. "AXButton").
+    pub role: String,
+    pub text: String,
+    pub bounds: AccessibilityNodeBounds,
+    /// Click point in normalized (0-1) coordinates: the center of `bounds`.
+    pub center_x: f32,
+    pub center_y: f32,
+}
+
+/// Response type for the frame elements endpoint.
+#[derive(OaSchema, Serialize)]
+pub struct FrameElementsResponse {
+    pub frame_id: i64,
+    pub elements: Vec<InteractableElement>,
+}
+
+/// Classify an accessibility role as an action-relevant "kind", or `None` if
+/// the role isn't something an agent would click/type into (e.g. static text,
+/// scroll bars, images).
+fn classify_interactable_role(role: &str) -> Option<&'static str> {
+    match role {
+        "AXButton" | "AXMenuItem" | "AXMenuButton" | "AXPopUpButton" | "AXCheckBox"
+        | "AXRadioButton" | "AXDisclosureTriangle" | "AXTab" => Some("button"),
+        "AXLink" => Some("link"),
+        "AXTextField" | "AXTextArea" | "AXComboBox" | "AXSecureTextField" => Some("entry"),
+        _ => None,
+    }
+}
+
+/// Extract the interactable elements (buttons, links, form entries) from a
+/// frame's accessibility tree, with normalized bounds and a click-point
+/// center, so downstream computer-use agents can ground actions in historical
+/// screens without re-walking the live accessibility tree.
+#[oasgen]
+pub async fn get_frame_elements(
+    State(state): State<Arc<AppState>>,
+    Path(frame_id): Path<i64>,
+) -> Result<JsonResponse<FrameElementsResponse>, (StatusCode, JsonResponse<Value>)> {
+    let (_, tree_json) = match state.db.get_frame_accessibility_data(frame_id).await {
+        Ok(data) => data,
+        Err(e) => {
+            debug!(
+                "Accessibility data unavailable for frame {} ({}), no elements to extract",
+                frame_id, e
+            );
+            (None, None)
+        }
+    };
+
+    let Some(tree_json) = tree_json else {
+        return Ok(JsonResponse(FrameElementsResponse {
+            frame_id,
+            elements: Vec::new(),
+        }));
+    };
+
+    let Ok(parsed) = serde_json::from_str::<Vec<serde_json::Value>>(&tree_json) else {
+        return Ok(JsonResponse(FrameElementsResponse {
+            frame_id,
+            elements: Vec::new(),
+        }));
+    };
+
+    let elements = parsed
+        .iter()
+        .filter_map(|node_val| {
+            let role = node_val.get("role")?.as_str()?.to_string();
+            let kind = classify_interactable_role(&role)?;
+            let text = node_val
+                .get("text")
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string();
+            let b = node_val.get("bounds")?;
+            let bounds = AccessibilityNodeBounds {
+                left: b.get("left")?.as_f64()? as f32,
+                top: b.get("top")?.as_f64()? as f32,
+                width: b.get("width")?.as_f64()? as f32,
+                height: b.get("height")?.as_f64()? as f32,
+            };
+            if bounds.width <= 0.0 || bounds.height <= 0.0 {
+                return None;
+            }
+
+            Some(InteractableElement {
+                kind,
+                role,
+                center_x: bounds.left + bounds.width / 2.0,
+                center_y: bounds.top + bounds.height / 2.0,
+                text,
+                bounds,
+            })
+        })
+        .collect();
+
+    Ok(JsonResponse(FrameElementsResponse { frame_id, elements }))
+}
+
 /// Extract a URL from text if it looks like one
 fn extract_url_from_text(text: &str) -> Option<String> {
     let trimmed = text.trim();
@@ -682,6 +794,8 @@ pub async fn get_frame_ocr_data(
                         width,
                         height,
                     },
+                    char_start: None,
+                    char_end: None,
                 });
             }
         }
@@ -716,6 +830,8 @@ pub async fn get_frame_ocr_data(
                                 width,
                                 height,
                             },
+                            char_start: None,
+                            char_end: None,
                         })
                     })
                     .collect();
@@ -793,7 +909,17 @@ pub async fn run_frame_ocr(
         }
     } else {
         // Legacy video-chunk frame — extract via ffmpeg
-        match extract_frame_from_video(&file_path, offset_index, "95").await {
+        let local_path = match state.cold_storage.resolve_local_path(&file_path).await {
+            Ok(path) => path,
+            Err(e) => {
+                error!("Failed to resolve {}: {}", file_path, e);
+                return Err((
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    JsonResponse(json!({ "error": format!("Failed to resolve media file: {}", e) })),
+                ));
+            }
+        };
+        match extract_frame_from_video(&local_path, offset_index, "95").await {
             Ok(temp_path) => match tokio::task::spawn_blocking({
                 let p = temp_path.clone();
                 move || image::open(&p)
@@ -864,6 +990,66 @@ pub async fn run_frame_ocr(
     }))
 }
 
+/// Serve a snapshot frame at the requested tier ("thumbnail" or "medium"),
+/// generating and recording it on first request (lazy backfill) if the
+/// asset doesn't exist yet. Falls back to the full-resolution frame if the
+/// tier is unrecognized or generation fails.
+async fn serve_frame_tier(
+    state: &Arc<AppState>,
+    frame_id: i64,
+    frame_path: &str,
+    tier: &str,
+) -> Result<Response<Body>, (StatusCode, JsonResponse<Value>)> {
+    let Ok(tier) = tier.parse::<FrameAssetTier>() else {
+        debug!("Unrecognized frame tier '{}', serving full resolution", tier);
+        return serve_file(frame_path).await;
+    };
+
+    match state.db.get_frame_asset(frame_id, tier).await {
+        Ok(Some(asset)) => return serve_file(&asset.file_path).await,
+        Ok(None) => {} // not generated yet — backfill below
+        Err(e) => {
+            debug!("Failed to look up frame asset for frame {}: {}", frame_id, e);
+            return serve_file(frame_path).await;
+        }
+    }
+
+    let original_path = frame_path.to_string();
+    let tier_owned = tier;
+    let generated = tokio::task::spawn_blocking(move || {
+        let image = image::open(&original_path)?;
+        screenpipe_vision::write_tier(
+            &image,
+            std::path::Path::new(&original_path),
+            tier_owned,
+            70,
+        )
+    })
+    .await;
+
+    match generated {
+        Ok(Ok((path, width, height))) => {
+            let path_str = path.to_string_lossy().to_string();
+            if let Err(e) = state
+                .db
+                .insert_frame_asset(frame_id, tier, &path_str, width as i64, height as i64)
+                .await
+            {
+                debug!("Failed to record backfilled frame asset {}: {}", frame_id, e);
+            }
+            serve_file(&path_str).await
+        }
+        Ok(Err(e)) => {
+            debug!("Failed to backfill tier for frame {}: {}", frame_id, e);
+            serve_file(frame_path).await
+        }
+        Err(e) => {
+            debug!("Tier backfill task panicked for frame {}: {}", frame_id, e);
+            serve_file(frame_path).await
+        }
+    }
+}
+
 /// Apply PII redaction to a frame image
 pub(crate) async fn apply_pii_redaction(
     state: &Arc<AppState>,
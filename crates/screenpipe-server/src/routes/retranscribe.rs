@@ -8,7 +8,9 @@ use axum::{
     response::{IntoResponse, Json as JsonResponse, Response},
 };
 use chrono::{DateTime, Utc};
+use screenpipe_audio::audio_manager::AudioManager;
 use screenpipe_audio::transcription::VocabularyEntry;
+use screenpipe_db::{AudioChunkInfo, DatabaseManager};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::path::Path;
@@ -96,12 +98,36 @@ pub async fn retranscribe_handler(
 
     info!("retranscribe: found {} raw rows (may include dupes)", chunks.len());
 
-    // 2. Get transcription config from audio manager
-    let audio_manager = &state.audio_manager;
-    let vocabulary = request.vocabulary.unwrap_or_default();
+    match retranscribe_chunks(
+        &state.db,
+        &state.audio_manager,
+        chunks,
+        request.engine,
+        request.vocabulary.unwrap_or_default(),
+        request.prompt,
+    )
+    .await
+    {
+        Ok(response) => JsonResponse(json!(response)).into_response(),
+        Err(response) => response,
+    }
+}
 
+/// Re-transcribes `chunks` with `engine_override` (falling back to the audio
+/// manager's currently configured engine when `None` or unparseable) and
+/// writes the results back via [`DatabaseManager::replace_audio_transcription`].
+/// Shared by [`retranscribe_handler`] and the auto re-transcription background
+/// job, so both go through the exact same decode/STT/write path.
+pub(crate) async fn retranscribe_chunks(
+    db: &Arc<DatabaseManager>,
+    audio_manager: &Arc<AudioManager>,
+    chunks: Vec<AudioChunkInfo>,
+    engine_override: Option<String>,
+    vocabulary: Vec<VocabularyEntry>,
+    prompt: Option<String>,
+) -> Result<RetranscribeResponse, Response> {
     // Resolve engine
-    let engine = if let Some(ref engine_str) = request.engine {
+    let engine = if let Some(ref engine_str) = engine_override {
         use screenpipe_audio::core::engine::AudioTranscriptionEngine;
         match engine_str.parse::<AudioTranscriptionEngine>() {
             Ok(e) => Arc::new(e),
@@ -116,13 +142,14 @@ pub async fn retranscribe_handler(
 
     // Build effective vocabulary: user-provided + prompt words as bias entries
     let mut effective_vocabulary = vocabulary;
-    if let Some(ref prompt) = request.prompt {
+    if let Some(ref prompt) = prompt {
         for word in prompt.split_whitespace() {
             let word = word.trim_matches(|c: char| !c.is_alphanumeric());
             if !word.is_empty() && word.len() > 2 {
                 effective_vocabulary.push(VocabularyEntry {
                     word: word.to_string(),
                     replacement: None,
+                    boost: None,
                 });
             }
         }
@@ -146,10 +173,10 @@ pub async fn retranscribe_handler(
                     }
                     Err(e) => {
                         error!("failed to load qwen3-asr for retranscription: {}", e);
-                        return error_response(
+                        return Err(error_response(
                             StatusCode::INTERNAL_SERVER_ERROR,
                             format!("failed to load qwen3-asr: {}", e),
-                        );
+                        ));
                     }
                 }
             } else {
@@ -171,15 +198,15 @@ pub async fn retranscribe_handler(
                 // Qwen3-ASR doesn't need WhisperContext; create a dummy one won't work,
                 // so we handle this in the loop below
                 // For now, return error if whisper isn't loaded (we still need it for state creation)
-                return error_response(
+                return Err(error_response(
                     StatusCode::SERVICE_UNAVAILABLE,
                     "whisper model not loaded — audio recording may be disabled".into(),
-                );
+                ));
             } else {
-                return error_response(
+                return Err(error_response(
                     StatusCode::SERVICE_UNAVAILABLE,
                     "whisper model not loaded — audio recording may be disabled".into(),
-                );
+                ));
             }
         }
     };
@@ -228,7 +255,7 @@ pub async fn retranscribe_handler(
             }
         };
 
-        let transcription = match screenpipe_audio::stt(
+        let (transcription, confidence) = match screenpipe_audio::stt(
             &samples,
             sample_rate,
             chunk.device.as_deref().unwrap_or("unknown"),
@@ -241,7 +268,7 @@ pub async fn retranscribe_handler(
         )
         .await
         {
-            Ok(text) => text,
+            Ok(result) => result,
             Err(e) => {
                 error!("STT failed for chunk {}: {}", chunk.id, e);
                 continue;
@@ -255,8 +282,7 @@ pub async fn retranscribe_handler(
         let engine_name = engine.to_string();
         let timestamp = chunk.timestamp;
         let duration_secs = samples.len() as f64 / sample_rate as f64;
-        if let Err(e) = state
-            .db
+        if let Err(e) = db
             .replace_audio_transcription(
                 chunk.id,
                 &transcription,
@@ -265,6 +291,7 @@ pub async fn retranscribe_handler(
                 is_input,
                 timestamp,
                 Some(duration_secs),
+                confidence.map(|c| c as f64),
             )
             .await
         {
@@ -285,9 +312,8 @@ pub async fn retranscribe_handler(
 
     info!("retranscribe complete: {} chunks processed, {} transcription results", processed, results.len());
 
-    let response = RetranscribeResponse {
+    Ok(RetranscribeResponse {
         chunks_processed: processed,
         transcriptions: results,
-    };
-    JsonResponse(json!(response)).into_response()
+    })
 }
@@ -67,9 +67,30 @@ pub(crate) struct SearchQuery {
     /// Filter audio transcriptions by speaker name (case-insensitive partial match)
     #[serde(default)]
     speaker_name: Option<String>,
+    /// Only include audio transcriptions with at least this per-token
+    /// confidence (`0.0`-`1.0`). Transcriptions from engines that don't
+    /// report confidence (Deepgram, Qwen3-ASR) are never excluded by this
+    /// filter. Ignored for OCR/UI/Input results, which have no confidence.
+    #[serde(default)]
+    min_confidence: Option<f64>,
     /// Include cloud-synced data in search results (requires cloud sync to be enabled)
     #[serde(default)]
     include_cloud: bool,
+    /// Re-rank results by composite relevance (text match, recency, focus,
+    /// dwell time, source weighting) instead of by timestamp alone — see
+    /// `crate::search_ranking`.
+    #[serde(default)]
+    rank: bool,
+    /// When `rank` is set, also return the per-factor score breakdown for
+    /// each result in `SearchResponse::ranking`.
+    #[serde(default)]
+    explain: bool,
+    /// When the exact-match search returns too few results, retry with the
+    /// closest correction of each query word found in the FTS vocabulary
+    /// (see `crate::fuzzy_search`) and merge in whatever it finds. Merged
+    /// results are flagged in `SearchResponse::fuzzy_matches`.
+    #[serde(default)]
+    fuzzy: bool,
 }
 
 #[derive(OaSchema, Deserialize)]
@@ -97,6 +118,44 @@ pub struct SearchResponse {
     /// Metadata about cloud search availability (only present when cloud sync is available)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cloud: Option<crate::cloud_search::CloudSearchMetadata>,
+    /// Per-result ranking score breakdown, aligned by index with `data`.
+    /// Only present when the request set both `rank` and `explain`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ranking: Option<Vec<RankExplainDto>>,
+    /// The corrected query the fuzzy fallback retried with, if `fuzzy` was
+    /// set and the exact search was thin enough to trigger it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggested_query: Option<String>,
+    /// Per-result flag, aligned by index with `data`: `true` for results
+    /// only found via `suggested_query`, not the original exact search.
+    /// Only present when the fuzzy fallback actually ran.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fuzzy_matches: Option<Vec<bool>>,
+}
+
+/// Per-factor relevance score for one search result — see
+/// `crate::search_ranking::RankExplain`.
+#[derive(OaSchema, Serialize, Deserialize, Clone)]
+pub struct RankExplainDto {
+    pub text_relevance: f64,
+    pub recency: f64,
+    pub focus: f64,
+    pub dwell: f64,
+    pub source_weight: f64,
+    pub total: f64,
+}
+
+impl From<crate::search_ranking::RankExplain> for RankExplainDto {
+    fn from(explain: crate::search_ranking::RankExplain) -> Self {
+        Self {
+            text_relevance: explain.text_relevance,
+            recency: explain.recency,
+            focus: explain.focus,
+            dwell: explain.dwell,
+            source_weight: explain.source_weight,
+            total: explain.total,
+        }
+    }
 }
 
 /// Compute a cache key for a search query by hashing its parameters
@@ -117,10 +176,18 @@ pub(crate) fn compute_search_cache_key(query: &SearchQuery) -> u64 {
     query.focused.hash(&mut hasher);
     query.browser_url.hash(&mut hasher);
     query.speaker_name.hash(&mut hasher);
+    query.min_confidence.map(|c| c.to_bits()).hash(&mut hasher);
     query.include_cloud.hash(&mut hasher);
+    query.rank.hash(&mut hasher);
+    query.explain.hash(&mut hasher);
+    query.fuzzy.hash(&mut hasher);
     hasher.finish()
 }
 
+/// Below this many exact-match results, [`search`] considers the query
+/// thin enough to be worth retrying via [`crate::fuzzy_search::suggest`].
+const FUZZY_FALLBACK_THRESHOLD: i64 = 3;
+
 // Update the search function
 #[oasgen]
 pub(crate) async fn search(
@@ -175,10 +242,11 @@ pub(crate) async fn search(
             query.browser_url.as_deref(),
             query.focused,
             query.speaker_name.as_deref(),
+            query.min_confidence,
         ),
         state.db.count_search_results(
             query_str,
-            content_type,
+            content_type.clone(),
             query.start_time,
             query.end_time,
             query.app_name.as_deref(),
@@ -190,6 +258,7 @@ pub(crate) async fn search(
             query.browser_url.as_deref(),
             query.focused,
             query.speaker_name.as_deref(),
+            query.min_confidence,
         ),
     )
     .await
@@ -201,14 +270,87 @@ pub(crate) async fn search(
         )
     })?;
 
+    // If the exact search came back thin, retry with the closest FTS-vocab
+    // correction of each query word and merge in whatever it finds, marking
+    // the merged-in results as such (see `crate::fuzzy_search`).
+    let mut results = results;
+    let mut suggested_query: Option<String> = None;
+    let mut fuzzy_ids: std::collections::HashSet<(u8, i64)> = std::collections::HashSet::new();
+    if query.fuzzy && !query_str.is_empty() && total < FUZZY_FALLBACK_THRESHOLD as usize {
+        if let Some((corrected, _corrections)) = crate::fuzzy_search::suggest(&state.db, query_str).await
+        {
+            if corrected != query_str.to_lowercase() {
+                match state
+                    .db
+                    .search(
+                        &corrected,
+                        content_type.clone(),
+                        query.pagination.limit,
+                        query.pagination.offset,
+                        query.start_time,
+                        query.end_time,
+                        query.app_name.as_deref(),
+                        query.window_name.as_deref(),
+                        query.min_length,
+                        query.max_length,
+                        query.speaker_ids.clone(),
+                        query.frame_name.as_deref(),
+                        query.browser_url.as_deref(),
+                        query.focused,
+                        query.speaker_name.as_deref(),
+                        query.min_confidence,
+                    )
+                    .await
+                {
+                    Ok(fuzzy_results) => {
+                        let existing: std::collections::HashSet<(u8, i64)> = results
+                            .iter()
+                            .map(crate::fuzzy_search::result_key)
+                            .collect();
+                        for fuzzy_result in fuzzy_results {
+                            let key = crate::fuzzy_search::result_key(&fuzzy_result);
+                            if existing.contains(&key) {
+                                continue;
+                            }
+                            fuzzy_ids.insert(key);
+                            results.push(fuzzy_result);
+                        }
+                        suggested_query = Some(corrected);
+                    }
+                    Err(e) => error!("fuzzy search fallback failed: {}", e),
+                }
+            }
+        }
+    }
+
     // Helper to check if app name contains "screenpipe" (case insensitive)
     let is_screenpipe_app =
         |app_name: &str| -> bool { app_name.to_lowercase().contains("screenpipe") };
 
-    let mut content_items: Vec<ContentItem> = results
-        .iter()
+    // Re-rank by composite relevance if requested, carrying the per-result
+    // explain breakdown alongside so it survives the filter/map below in
+    // lockstep with its result — see `crate::search_ranking`.
+    let ranked: Vec<(SearchResult, Option<crate::search_ranking::RankExplain>)> = if query.rank {
+        crate::search_ranking::rank(
+            &state.db,
+            query_str,
+            results,
+            &crate::search_ranking::RankingWeights::default(),
+        )
+        .await
+        .into_iter()
+        .map(|ranked| (ranked.result, Some(ranked.explain)))
+        .collect()
+    } else {
+        results.into_iter().map(|result| (result, None)).collect()
+    };
+
+    let mut rank_explain: Vec<RankExplainDto> = Vec::new();
+    let mut fuzzy_matches: Vec<bool> = Vec::new();
+    let mut content_items: Vec<ContentItem> = ranked
+        .into_iter()
         // Filter out screenpipe results at display time
-        .filter(|result| match result {
+        .filter(|(result, _)| match result {
             SearchResult::OCR(ocr) => !is_screenpipe_app(&ocr.app_name),
             SearchResult::Audio(_) => true, // Audio doesn't have app_name
             SearchResult::UI(ui) => !is_screenpipe_app(&ui.app_name),
@@ -217,7 +359,18 @@ pub(crate) async fn search(
                 .as_ref()
                 .is_none_or(|app| !is_screenpipe_app(app)),
         })
-        .map(|result| match result {
+        .map(|(result, explain)| {
+            if query.explain {
+                if let Some(explain) = explain {
+                    rank_explain.push(explain.into());
+                }
+            }
+            if suggested_query.is_some() {
+                fuzzy_matches.push(fuzzy_ids.contains(&crate::fuzzy_search::result_key(&result)));
+            }
+            result
+        })
+        .map(|result| match &result {
             SearchResult::OCR(ocr) => ContentItem::OCR(OCRContent {
                 frame_id: ocr.frame_id,
                 text: ocr.ocr_text.clone(),
@@ -245,6 +398,9 @@ pub(crate) async fn search(
                 speaker: audio.speaker.clone(),
                 start_time: audio.start_time,
                 end_time: audio.end_time,
+                summary: audio.summary.clone(),
+                source_app: audio.source_app.clone(),
+                confidence: audio.confidence,
             }),
             SearchResult::UI(ui) => ContentItem::UI(UiContent {
                 id: ui.id,
@@ -337,6 +493,14 @@ pub(crate) async fn search(
         None
     };
 
+    let ranking = if query.rank && query.explain {
+        Some(rank_explain)
+    } else {
+        None
+    };
+
+    let fuzzy_matches = suggested_query.as_ref().map(|_| fuzzy_matches);
+
     let response = SearchResponse {
         data: content_items,
         pagination: PaginationInfo {
@@ -345,6 +509,9 @@ pub(crate) async fn search(
             total: total as i64,
         },
         cloud,
+        ranking,
+        suggested_query,
+        fuzzy_matches,
     };
 
     // Cache the result (only for queries without frame extraction)
@@ -358,12 +525,160 @@ pub(crate) async fn search(
     Ok(JsonResponse(response))
 }
 
+#[derive(OaSchema, Deserialize)]
+pub(crate) struct AudioSearchQuery {
+    #[serde(default)]
+    q: Option<String>,
+    #[serde(flatten)]
+    pagination: PaginationQuery,
+    #[serde(default)]
+    start_time: Option<DateTime<Utc>>,
+    #[serde(default)]
+    end_time: Option<DateTime<Utc>>,
+    #[serde(default)]
+    min_length: Option<usize>,
+    #[serde(default)]
+    max_length: Option<usize>,
+    #[serde(
+        deserialize_with = "from_comma_separated_array",
+        default = "default_speaker_ids"
+    )]
+    speaker_ids: Option<Vec<i64>>,
+    speaker_name: Option<String>,
+    /// Filter by the app that was focused when the (output-device) transcript
+    /// was captured — see `screenpipe_db::AudioResult::source_app`.
+    #[serde(default)]
+    source_app: Option<String>,
+    /// Only include transcriptions with at least this confidence — see
+    /// `screenpipe_db::AudioResult::confidence`.
+    #[serde(default)]
+    min_confidence: Option<f64>,
+}
+
+/// GET /audio/search — a dedicated audio search endpoint exposing filters
+/// (in particular `source_app`) that the generic `/search` endpoint doesn't
+/// thread through `DatabaseManager::search`'s already very wide parameter
+/// list. Wraps `DatabaseManager::search_audio` directly.
+#[oasgen]
+pub(crate) async fn audio_search(
+    Query(query): Query<AudioSearchQuery>,
+    State(state): State<Arc<AppState>>,
+) -> Result<JsonResponse<Vec<AudioContent>>, (StatusCode, JsonResponse<Value>)> {
+    let results = state
+        .db
+        .search_audio(
+            query.q.as_deref().unwrap_or(""),
+            query.pagination.limit,
+            query.pagination.offset,
+            query.start_time,
+            query.end_time,
+            query.min_length,
+            query.max_length,
+            query.speaker_ids,
+            query.speaker_name.as_deref(),
+            query.source_app.as_deref(),
+            query.min_confidence,
+        )
+        .await
+        .map_err(|e| {
+            error!("failed to perform audio search: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                JsonResponse(json!({"error": format!("failed to perform audio search: {}", e)})),
+            )
+        })?;
+
+    Ok(JsonResponse(
+        results
+            .into_iter()
+            .map(|audio| AudioContent {
+                chunk_id: audio.audio_chunk_id,
+                transcription: audio.transcription,
+                timestamp: audio.timestamp,
+                file_path: audio.file_path,
+                offset_index: audio.offset_index,
+                tags: audio.tags,
+                device_name: audio.device_name,
+                device_type: audio.device_type.into(),
+                speaker: audio.speaker,
+                start_time: audio.start_time,
+                end_time: audio.end_time,
+                summary: audio.summary,
+                source_app: audio.source_app,
+                confidence: audio.confidence,
+            })
+            .collect(),
+    ))
+}
+
 #[oasgen]
 pub(crate) async fn keyword_search_handler(
     Query(query): Query<KeywordSearchRequest>,
     State(state): State<Arc<AppState>>,
 ) -> Result<JsonResponse<Value>, (StatusCode, JsonResponse<Value>)> {
-    if query.group {
+    // Compile `AND`/`OR`/`NOT`, quoted phrases, proximity, and `app:`/`window:`/
+    // `before:`/`after:` field scoping (see `screenpipe_db::query_language`) into an
+    // FTS5 expression plus filters. Plain queries that use none of that syntax fall
+    // through unchanged, keeping today's fuzzy-match behavior for the common case.
+    let parsed = screenpipe_db::query_language::parse_search_query(&query.query);
+    let uses_query_language = parsed.has_operators
+        || parsed.app_name.is_some()
+        || parsed.window_name.is_some()
+        || parsed.before.is_some()
+        || parsed.after.is_some();
+
+    let (effective_query, raw_fts, fuzzy_match) = if uses_query_language {
+        (parsed.fts_query.clone(), true, false)
+    } else {
+        (query.query.clone(), false, query.fuzzy_match)
+    };
+    let app_names = parsed
+        .app_name
+        .clone()
+        .map(|app| vec![app])
+        .or_else(|| query.app_names.clone());
+    let window_name = parsed.window_name.clone();
+    let start_time = parsed.after.or(query.start_time);
+    let end_time = parsed.before.or(query.end_time);
+
+    if query.bucket {
+        // Same flat query as the default branch, then aggregated into 5-minute
+        // sessions in Rust so the timeline UI gets one response per view instead
+        // of one request per frame.
+        let matches = state
+            .db
+            .search_with_text_positions(
+                &effective_query,
+                query.limit,
+                query.offset,
+                start_time,
+                end_time,
+                fuzzy_match,
+                query.order,
+                app_names.clone(),
+                None,
+                query.domain.clone(),
+                window_name.clone(),
+                raw_fts,
+                query.tag.clone(),
+            )
+            .await
+            .map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    JsonResponse(json!({"error": e.to_string()})),
+                )
+            })?;
+
+        let filtered: Vec<_> = matches
+            .into_iter()
+            .filter(|m| !m.app_name.to_lowercase().contains("screenpipe"))
+            .collect();
+
+        let buckets = DatabaseManager::bucket_search_matches(filtered, 5 * 60);
+
+        Ok(JsonResponse(json!(buckets)))
+    } else if query.group {
         // Lightweight query: skips text/text_json columns (no OCR blob reads,
         // no JSON parsing). max_per_app=30 ensures app diversity via ROW_NUMBER.
         // FTS subquery capped at 5000 to limit scan. Typically <200ms.
@@ -400,15 +715,19 @@ pub(crate) async fn keyword_search_handler(
         let matches = state
             .db
             .search_with_text_positions(
-                &query.query,
+                &effective_query,
                 query.limit,
                 query.offset,
-                query.start_time,
-                query.end_time,
-                query.fuzzy_match,
+                start_time,
+                end_time,
+                fuzzy_match,
                 query.order,
-                query.app_names,
+                app_names,
                 None, // no per-app limit for flat results
+                query.domain.clone(),
+                window_name,
+                raw_fts,
+                query.tag.clone(),
             )
             .await
             .map_err(|e| {
@@ -457,6 +776,19 @@ pub struct KeywordSearchRequest {
     app_names: Option<Vec<String>>,
     #[serde(default)]
     group: bool,
+    /// Aggregate results into 5-minute time buckets (snippet, dominant app, hit count,
+    /// representative frame id per bucket) instead of returning flat frame rows.
+    #[serde(default)]
+    bucket: bool,
+    /// Restrict results to frames captured from this browser domain (e.g. "stripe.com")
+    #[serde(default)]
+    domain: Option<String>,
+    /// Restrict results to frames tagged with this tag name (see `/tags`).
+    /// Category filters (coding, communication, browsing, media, ...) from
+    /// `crate::activity_classifier` use this same mechanism — pass
+    /// `category:coding` to filter to that category.
+    #[serde(default)]
+    tag: Option<String>,
 }
 
 // Helper functions
@@ -468,6 +800,119 @@ pub(crate) fn default_speaker_ids() -> Option<Vec<i64>> {
     None
 }
 
+pub(crate) fn default_instant_search_limit() -> usize {
+    20
+}
+
+#[derive(OaSchema, Deserialize)]
+pub(crate) struct InstantSearchQuery {
+    q: String,
+    #[serde(default = "default_instant_search_limit")]
+    limit: usize,
+}
+
+/// Search-overlay endpoint backed entirely by the in-memory hot cache
+/// (today's OCR'd frames + audio transcriptions) — no DB round-trip, so it
+/// stays well under the latency budget needed to filter results as the user
+/// types. Falls back to nothing for anything older than what the hot cache
+/// holds; the regular `/search` endpoint remains the source of truth for that.
+#[oasgen]
+pub(crate) async fn instant_search_handler(
+    Query(query): Query<InstantSearchQuery>,
+    State(state): State<Arc<AppState>>,
+) -> JsonResponse<Vec<crate::hot_frame_cache::InstantSearchHit>> {
+    JsonResponse(
+        state
+            .hot_frame_cache
+            .instant_search(&query.q, query.limit)
+            .await,
+    )
+}
+
+pub(crate) fn default_realtime_context_minutes() -> i64 {
+    5
+}
+
+#[derive(OaSchema, Deserialize)]
+pub(crate) struct RealtimeContextQuery {
+    #[serde(default = "default_realtime_context_minutes")]
+    minutes: i64,
+}
+
+/// Realtime-context endpoint for AI agents that need "what has the user
+/// been doing for the last few minutes" — frames, transcripts, and UI
+/// events straight from the in-memory hot cache, no DB round-trip. See
+/// `crate::hot_frame_cache::HotFrameCache::recent_context`.
+#[oasgen]
+pub(crate) async fn realtime_context_handler(
+    Query(query): Query<RealtimeContextQuery>,
+    State(state): State<Arc<AppState>>,
+) -> JsonResponse<crate::hot_frame_cache::RealtimeContext> {
+    JsonResponse(state.hot_frame_cache.recent_context(query.minutes).await)
+}
+
+fn default_context_limit() -> u32 {
+    100
+}
+
+fn default_context_token_budget() -> usize {
+    4000
+}
+
+#[derive(OaSchema, Deserialize)]
+pub(crate) struct BuildContextQuery {
+    #[serde(default)]
+    q: Option<String>,
+    #[serde(default)]
+    start_time: Option<DateTime<Utc>>,
+    #[serde(default)]
+    end_time: Option<DateTime<Utc>>,
+    #[serde(default = "default_context_limit")]
+    limit: u32,
+    #[serde(default = "default_context_token_budget")]
+    token_budget: usize,
+}
+
+#[derive(Serialize, OaSchema)]
+pub(crate) struct BuildContextResponse {
+    context: String,
+    evidence: Vec<crate::evidence::EvidenceItem>,
+    truncated: bool,
+}
+
+/// GET /context — token-budgeted, deduplicated, chronologically ordered
+/// context string over captured data, for AI agents/pipes that need to build
+/// a prompt without reimplementing search + dedup + budgeting themselves.
+/// See `crate::context_builder::ContextBuilder`.
+#[oasgen]
+pub(crate) async fn build_context_handler(
+    Query(query): Query<BuildContextQuery>,
+    State(state): State<Arc<AppState>>,
+) -> Result<JsonResponse<BuildContextResponse>, (StatusCode, JsonResponse<Value>)> {
+    let result = crate::context_builder::ContextBuilder::new(&state.db)
+        .build(crate::context_builder::ContextRequest {
+            query: query.q.as_deref().unwrap_or(""),
+            start_time: query.start_time,
+            end_time: query.end_time,
+            limit: query.limit,
+            token_budget: query.token_budget,
+        })
+        .await
+        .map_err(|e| {
+            error!("failed to build context: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                JsonResponse(json!({"error": format!("failed to build context: {}", e)})),
+            )
+        })?;
+
+    Ok(JsonResponse(BuildContextResponse {
+        context: result.context,
+        evidence: result.evidence,
+        truncated: result.truncated,
+    }))
+}
+
 pub(crate) fn from_comma_separated_array<'de, D>(
     deserializer: D,
 ) -> Result<Option<Vec<i64>>, D::Error>
@@ -102,6 +102,25 @@ pub struct GetSimilarSpeakersRequest {
     limit: u32,
 }
 
+#[derive(OaSchema, Deserialize, Debug)]
+pub struct ListSpeakersRequest {
+    limit: u32,
+    offset: u32,
+    #[serde(default)]
+    include_hidden: bool,
+}
+
+#[derive(OaSchema, Deserialize, Debug)]
+pub(crate) struct HideSpeakerRequest {
+    speaker_id: i64,
+    #[serde(default = "default_hidden")]
+    hidden: bool,
+}
+
+fn default_hidden() -> bool {
+    true
+}
+
 #[oasgen]
 pub(crate) async fn get_unnamed_speakers_handler(
     State(state): State<Arc<AppState>>,
@@ -138,6 +157,61 @@ pub(crate) async fn get_unnamed_speakers_handler(
     Ok(JsonResponse(speakers))
 }
 
+#[oasgen]
+pub(crate) async fn list_speakers_handler(
+    State(state): State<Arc<AppState>>,
+    Query(request): Query<ListSpeakersRequest>,
+) -> Result<JsonResponse<Vec<Speaker>>, (StatusCode, JsonResponse<Value>)> {
+    let speakers = state
+        .db
+        .list_speakers(request.limit, request.offset, request.include_hidden)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                JsonResponse(json!({"error": e.to_string()})),
+            )
+        })?;
+
+    // convert metadata to json, same as get_unnamed_speakers_handler
+    let speakers = speakers
+        .into_iter()
+        .map(|speaker| {
+            let mut metadata: Value = serde_json::from_str(&speaker.metadata).unwrap_or(json!({}));
+            if let Some(audio_samples) = metadata.get("audio_samples").and_then(|v| v.as_array()) {
+                if let Ok(samples) = serde_json::to_value(audio_samples) {
+                    metadata["audio_samples"] = samples;
+                }
+            }
+            Speaker {
+                metadata: metadata.to_string(),
+                ..speaker
+            }
+        })
+        .collect();
+
+    Ok(JsonResponse(speakers))
+}
+
+#[oasgen]
+pub(crate) async fn hide_speaker_handler(
+    State(state): State<Arc<AppState>>,
+    Json(payload): Json<HideSpeakerRequest>,
+) -> Result<JsonResponse<Value>, (StatusCode, JsonResponse<Value>)> {
+    state
+        .db
+        .hide_speaker(payload.speaker_id, payload.hidden)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                JsonResponse(json!({"error": e.to_string()})),
+            )
+        })?;
+
+    Ok(JsonResponse(json!({"success": true})))
+}
+
 #[oasgen]
 pub(crate) async fn update_speaker_handler(
     State(state): State<Arc<AppState>>,
@@ -13,11 +13,13 @@ use axum::{
 };
 use oasgen::OaSchema;
 
-use screenpipe_events::{send_event, subscribe_to_all_events, Event as ScreenpipeEvent};
+use screenpipe_events::{send_event, subscribe_to_all_events, Event as ScreenpipeEvent, EventManager};
 
+use chrono::{DateTime, Utc};
 use futures::{SinkExt, StreamExt};
 use serde::Deserialize;
 use std::{
+    collections::HashMap,
     sync::{
         atomic::{AtomicUsize, Ordering},
         Arc,
@@ -26,8 +28,10 @@ use std::{
 };
 use tracing::{debug, error};
 
+use crate::realtime_captions::{CaptionSegment, CAPTION_EVENT_NAME};
 use crate::server::AppState;
 
+use super::events_stream::{backfill_events, bus_replay_events, EventStreamQuery};
 use super::health::health_check;
 
 /// Maximum number of concurrent WebSocket connections allowed.
@@ -87,6 +91,15 @@ pub(crate) fn try_acquire_ws_connection(counter: &Arc<AtomicUsize>) -> Option<Ws
 #[derive(OaSchema, Deserialize)]
 pub(crate) struct EventsQuery {
     images: Option<bool>,
+    /// Comma-separated event-type filter, e.g. "new_frame,ui_event".
+    /// Omit to receive every event type.
+    types: Option<String>,
+    /// Replay `new_frame`/`ui_event` events recorded since this timestamp
+    /// before switching to live streaming. See [`EventStreamQuery`].
+    since: Option<DateTime<Utc>>,
+    /// Consumer group name to replay bus-registered topics for. See
+    /// [`EventStreamQuery::consumer_group`].
+    consumer_group: Option<String>,
 }
 
 // websocket events handler
@@ -97,7 +110,7 @@ pub(crate) async fn ws_events_handler(
 ) -> Response {
     // Check connection limit before upgrading
     match try_acquire_ws_connection(&state.ws_connection_count) {
-        Some(guard) => ws.on_upgrade(|socket| handle_socket(socket, query, guard)),
+        Some(guard) => ws.on_upgrade(move |socket| handle_socket(socket, state, query, guard)),
         None => Response::builder()
             .status(StatusCode::SERVICE_UNAVAILABLE)
             .body(Body::from("Too many WebSocket connections"))
@@ -105,7 +118,12 @@ pub(crate) async fn ws_events_handler(
     }
 }
 
-async fn handle_socket(socket: WebSocket, query: Query<EventsQuery>, _guard: WsConnectionGuard) {
+async fn handle_socket(
+    socket: WebSocket,
+    state: Arc<AppState>,
+    query: Query<EventsQuery>,
+    _guard: WsConnectionGuard,
+) {
     let (mut sender, mut receiver) = socket.split();
 
     let incoming = tokio::spawn(async move {
@@ -120,17 +138,64 @@ async fn handle_socket(socket: WebSocket, query: Query<EventsQuery>, _guard: WsC
     // Handle the WebSocket connection here
     // You can add your logic to handle messages, upgrades, etc.
 
+    let stream_query = EventStreamQuery {
+        types: query.types.clone(),
+        since: query.since,
+        consumer_group: query.consumer_group.clone(),
+    };
+    let backfill = backfill_events(&state, &stream_query).await;
+    let bus_replay = bus_replay_events(&stream_query);
+    let live_group = query.consumer_group.clone();
+
     let outgoing = tokio::spawn(async move {
+        for (_, mut event) in backfill {
+            if !query.images.unwrap_or(false) && (event.name == "ocr_result" || event.name == "ui_frame") {
+                if let Some(data) = event.data.as_object_mut() {
+                    data.remove("image");
+                }
+            }
+            if let Err(e) = sender
+                .send(Message::Text(serde_json::to_string(&event).unwrap_or_default()))
+                .await
+            {
+                tracing::error!("Failed to send backfilled websocket message: {}", e);
+                return;
+            }
+        }
+
+        for mut event in bus_replay {
+            if !query.images.unwrap_or(false) && (event.name == "ocr_result" || event.name == "ui_frame") {
+                if let Some(data) = event.data.as_object_mut() {
+                    data.remove("image");
+                }
+            }
+            if let Err(e) = sender
+                .send(Message::Text(serde_json::to_string(&event).unwrap_or_default()))
+                .await
+            {
+                tracing::error!("Failed to send bus-replayed websocket message: {}", e);
+                return;
+            }
+        }
+
         let mut stream = subscribe_to_all_events();
         loop {
             tokio::select! {
                 event = stream.next() => {
                     if let Some(mut event) = event {
+                        if !stream_query.wants(&event.name) {
+                            continue;
+                        }
                         if !query.images.unwrap_or(false) && (event.name == "ocr_result" || event.name == "ui_frame") {
                             if let Some(data) = event.data.as_object_mut() {
                                 data.remove("image");
                             }
                         }
+                        if let Some(group) = &live_group {
+                            if let Some(offset) = EventManager::instance().latest_offset(&event.name) {
+                                EventManager::instance().ack(&event.name, group, offset);
+                            }
+                        }
                         if let Err(e) = sender
                             .send(Message::Text(
                                 serde_json::to_string(&event).unwrap_or_default(),
@@ -236,6 +301,10 @@ async fn handle_metrics_socket(
                         "chunks_sent": audio.chunks_sent,
                         "total_words": audio.total_words,
                         "audio_level_rms": audio.audio_level_rms,
+                        "reconciliation_running": audio.reconciliation_running,
+                        "reconciliation_chunks_total": audio.reconciliation_chunks_total,
+                        "reconciliation_chunks_done": audio.reconciliation_chunks_done,
+                        "reconciliation_eta_secs": audio.reconciliation_eta_secs,
                     },
                     "vision": {
                         "frames_captured": vision.frames_captured,
@@ -258,3 +327,63 @@ async fn handle_metrics_socket(
 
     debug!("Metrics WebSocket connection closed");
 }
+
+/// Live-only caption feed for OBS overlays and accessibility captioning
+/// tools — see `crate::realtime_captions` for the schema and its "Scope,
+/// honestly" limitations. No `since`/backfill, unlike `/ws/events`.
+pub(crate) async fn ws_captions_handler(
+    ws: WebSocketUpgrade,
+    State(state): State<Arc<AppState>>,
+) -> Response {
+    match try_acquire_ws_connection(&state.ws_connection_count) {
+        Some(guard) => ws.on_upgrade(move |socket| handle_captions_socket(socket, state, guard)),
+        None => Response::builder()
+            .status(StatusCode::SERVICE_UNAVAILABLE)
+            .body(Body::from("Too many WebSocket connections"))
+            .unwrap(),
+    }
+}
+
+async fn handle_captions_socket(mut socket: WebSocket, state: Arc<AppState>, _guard: WsConnectionGuard) {
+    let mut speaker_names: HashMap<i64, String> = HashMap::new();
+    let mut stream = subscribe_to_all_events();
+
+    loop {
+        tokio::select! {
+            event = stream.next() => {
+                let Some(event) = event else { break };
+                if event.name != CAPTION_EVENT_NAME {
+                    continue;
+                }
+                let Ok(mut segment) = serde_json::from_value::<CaptionSegment>(event.data) else {
+                    continue;
+                };
+                if let Some(speaker_id) = segment.speaker_id {
+                    if let Some(name) = speaker_names.get(&speaker_id) {
+                        segment.speaker_name = Some(name.clone());
+                    } else if let Ok(speaker) = state.db.get_speaker_by_id(speaker_id).await {
+                        if !speaker.name.is_empty() {
+                            speaker_names.insert(speaker_id, speaker.name.clone());
+                            segment.speaker_name = Some(speaker.name);
+                        }
+                    }
+                }
+                if let Err(e) = socket
+                    .send(Message::Text(serde_json::to_string(&segment).unwrap_or_default()))
+                    .await
+                {
+                    error!("Failed to send caption: {}", e);
+                    break;
+                }
+            }
+            result = socket.recv() => {
+                if result.is_none() {
+                    break;
+                }
+            }
+        }
+    }
+
+    debug!("Captions WebSocket connection closed");
+    // _guard is dropped here, decrementing the connection counter
+}
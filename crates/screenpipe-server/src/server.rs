@@ -20,27 +20,37 @@ use crate::{
             api_list_audio_devices, start_audio, start_audio_device, stop_audio, stop_audio_device,
         },
         content::{
-            add_tags, add_to_database, execute_raw_sql, get_tags_batch, merge_frames_handler,
-            remove_tags, validate_media_handler,
+            add_bookmark, add_tags, add_to_database, execute_raw_sql, get_tags_batch,
+            list_bookmarks, list_content_ids_by_tag, merge_frames_handler, remove_bookmark,
+            remove_tags, tag_time_range, validate_media_handler,
         },
         data::delete_time_range_handler,
         frames::{
-            get_frame_context, get_frame_data, get_frame_metadata, get_frame_ocr_data,
-            get_next_valid_frame, run_frame_ocr,
+            get_frame_context, get_frame_data, get_frame_elements, get_frame_metadata,
+            get_frame_ocr_data, get_next_valid_frame, run_frame_ocr,
         },
         health::{
-            api_list_monitors, api_vision_status, audio_metrics_handler, health_check,
-            vision_metrics_handler,
+            api_list_monitors, api_vision_status, audio_metrics_handler, get_entity_handler,
+            health_check, list_capture_gaps_handler, search_entities_handler,
+            search_frame_codes_handler, vision_metrics_handler,
         },
         meetings::{get_meeting_handler, list_meetings_handler},
-        search::{keyword_search_handler, search},
+        scenes::{get_scene_handler, list_scenes_handler, search_scenes_handler},
+        search::{
+            audio_search, build_context_handler, instant_search_handler, keyword_search_handler,
+            realtime_context_handler, search,
+        },
         speakers::{
             delete_speaker_handler, get_similar_speakers_handler, get_unnamed_speakers_handler,
-            mark_as_hallucination_handler, merge_speakers_handler, reassign_speaker_handler,
-            search_speakers_handler, undo_speaker_reassign_handler, update_speaker_handler,
+            hide_speaker_handler, list_speakers_handler, mark_as_hallucination_handler,
+            merge_speakers_handler, reassign_speaker_handler, search_speakers_handler,
+            undo_speaker_reassign_handler, update_speaker_handler,
         },
         streaming::{handle_video_export_post, handle_video_export_ws, stream_frames_handler},
-        websocket::{ws_events_handler, ws_health_handler, ws_metrics_handler},
+        tasks::{create_task, delete_task, get_task, list_tasks, update_task},
+        usage::get_usage_summary_handler,
+        events_stream::sse_events_handler,
+        websocket::{ws_captions_handler, ws_events_handler, ws_health_handler, ws_metrics_handler},
     },
     sync_api::{self, SyncState},
     video_cache::FrameCache,
@@ -116,6 +126,63 @@ pub struct AppState {
     pub hot_frame_cache: Arc<HotFrameCache>,
     /// Cloud archive state (initialized via /archive/init endpoint)
     pub archive_state: crate::archive::ArchiveState,
+    /// Webhook delivery subsystem — listens on the event bus and dispatches
+    /// HMAC-signed deliveries to registered URLs.
+    pub webhook_manager: Arc<crate::webhooks::WebhookManager>,
+    /// Direct machine-to-machine sync — pushes unsynced rows to paired
+    /// peers and decrypts/imports what they push back.
+    pub p2p_sync: Arc<crate::p2p_sync::P2pSyncManager>,
+    /// Cold-storage tiering for old media files — moves them to a configured
+    /// folder and transparently fetches them back on demand.
+    pub cold_storage: Arc<crate::cold_storage::ColdStorageManager>,
+    /// Daily Markdown notes exporter — writes meetings, app sessions,
+    /// bookmarks, and clipboard highlights into a notes vault.
+    pub daily_notes: Arc<crate::daily_notes::DailyNotesManager>,
+    /// Once-a-day digest of yesterday's stats (top apps, meetings, longest
+    /// focus block, bookmarks) — stored and notified.
+    pub daily_digest: Arc<crate::daily_digest::DailyDigestManager>,
+    /// Live migration of the data directory (DB + media) to a new storage
+    /// root, e.g. an external drive.
+    pub data_dir_migration: Arc<crate::data_dir_migration::DataDirMigrationManager>,
+    /// Once-a-day clustering of the previous day's embedded text into
+    /// labeled topics, for a timeline overview.
+    pub topic_clustering: Arc<crate::topic_clustering::TopicClusteringManager>,
+    /// Grounded Q&A over captured data via a configurable local/remote LLM
+    /// provider (Ollama, an OpenAI-compatible endpoint, or llama.cpp).
+    pub llm: Arc<crate::llm::LlmManager>,
+    /// Per-provider spend tracking and monthly budget caps for paid cloud
+    /// APIs (currently just the LLM's OpenAI-compatible provider).
+    pub usage_accounting: Arc<crate::usage_accounting::UsageManager>,
+    /// Heartbeat-based staleness detection for the vision and accessibility
+    /// pipelines (audio supervises itself, see `crate::watchdog`'s module doc).
+    pub watchdog: Arc<crate::watchdog::WatchdogManager>,
+    /// Self-throttling based on this process's own CPU/memory footprint —
+    /// see `crate::resource_governor`'s module doc for what it can and can't
+    /// degrade.
+    pub resource_governor: Arc<crate::resource_governor::ResourceGovernorManager>,
+    /// On-battery capture/backfill policy — see `crate::battery_policy`'s
+    /// module doc for what it can and can't degrade.
+    pub battery_policy: Arc<crate::battery_policy::BatteryPolicyManager>,
+    /// Tags newly captured frames with `category:<name>` based on app/
+    /// window/URL rules — see `crate::activity_classifier`'s module doc.
+    pub activity_classifier: Arc<crate::activity_classifier::ActivityClassifierManager>,
+    /// Tracks files opened/saved and links them to the active frame — see
+    /// `crate::file_activity`'s module doc.
+    pub file_activity: Arc<crate::file_activity::FileActivityManager>,
+    /// Captures desktop notifications as searchable events — see
+    /// `crate::notification_capture`'s module doc.
+    pub notification_capture: Arc<crate::notification_capture::NotificationCaptureManager>,
+    /// Re-transcribes low-confidence audio chunks once a higher-quality
+    /// engine than the one that originally produced them is configured —
+    /// see `crate::auto_retranscribe`'s module doc.
+    pub auto_retranscribe: Arc<crate::auto_retranscribe::AutoRetranscribeManager>,
+    /// Status for the media migration key — see `crate::media_migration`'s
+    /// module doc for what it does and doesn't cover.
+    pub media_migration: crate::media_migration::MediaMigrationManager,
+    /// Bearer-token auth, per-endpoint rate limiting, and an audit log for
+    /// the local HTTP API. `None` means auth is disabled (the default) —
+    /// see `crate::auth`'s module doc for scope.
+    pub auth: Option<Arc<crate::auth::AuthManager>>,
 }
 
 pub struct SCServer {
@@ -133,6 +200,19 @@ pub struct SCServer {
     pub audio_metrics: Arc<screenpipe_audio::metrics::AudioPipelineMetrics>,
     /// Shared hot frame cache — set this before starting the server so AppState uses it.
     pub hot_frame_cache: Option<Arc<HotFrameCache>>,
+    /// Set this before starting the server so the resource governor can
+    /// actually throttle capture FPS — `SCServer` has no `VisionManager`
+    /// handle of its own to build one from. Falls back to a governor with
+    /// no vision handle (measure + notify only) if left unset.
+    pub resource_governor: Option<Arc<crate::resource_governor::ResourceGovernorManager>>,
+    /// Set this before starting the server so the battery policy can
+    /// actually throttle capture FPS — same reasoning as `resource_governor`.
+    /// Falls back to a policy with no vision handle (status-only) if unset.
+    pub battery_policy: Option<Arc<crate::battery_policy::BatteryPolicyManager>>,
+    /// Set this before starting the server to require a bearer token on the
+    /// local HTTP API (see `crate::auth`). Left unset, the API stays
+    /// unauthenticated — the historical, backward-compatible default.
+    pub auth: Option<Arc<crate::auth::AuthManager>>,
 }
 
 impl SCServer {
@@ -162,6 +242,9 @@ impl SCServer {
             vision_metrics: Arc::new(screenpipe_vision::PipelineMetrics::new()),
             audio_metrics,
             hot_frame_cache: None,
+            resource_governor: None,
+            battery_policy: None,
+            auth: None,
         }
     }
 
@@ -322,6 +405,17 @@ impl SCServer {
             });
         }
 
+        let usage_accounting = crate::usage_accounting::UsageManager::new();
+        let topic_clustering = crate::topic_clustering::TopicClusteringManager::spawn(self.db.clone());
+
+        let battery_policy = self
+            .battery_policy
+            .clone()
+            .unwrap_or_else(|| crate::battery_policy::BatteryPolicyManager::spawn(None));
+        battery_policy
+            .set_topic_clustering(topic_clustering.clone())
+            .await;
+
         let app_state = Arc::new(AppState {
             db: self.db.clone(),
             audio_manager: self.audio_manager.clone(),
@@ -371,6 +465,42 @@ impl SCServer {
             frame_extraction_semaphore: Arc::new(tokio::sync::Semaphore::new(3)),
             hot_frame_cache,
             archive_state: crate::archive::ArchiveState::new(),
+            webhook_manager: crate::webhooks::WebhookManager::spawn(self.db.clone()),
+            p2p_sync: crate::p2p_sync::P2pSyncManager::spawn(
+                self.db.clone(),
+                crate::cli::get_or_create_machine_id(None),
+            ),
+            cold_storage: crate::cold_storage::ColdStorageManager::spawn(self.db.clone()),
+            daily_notes: crate::daily_notes::DailyNotesManager::spawn(self.db.clone()),
+            daily_digest: crate::daily_digest::DailyDigestManager::spawn(self.db.clone()),
+            data_dir_migration: crate::data_dir_migration::DataDirMigrationManager::new(
+                self.db.clone(),
+            ),
+            topic_clustering,
+            llm: crate::llm::LlmManager::new(self.db.clone(), usage_accounting.clone()),
+            usage_accounting,
+            watchdog: crate::watchdog::WatchdogManager::spawn(
+                self.vision_metrics.clone(),
+                self.audio_metrics.clone(),
+                self.db.clone(),
+            ),
+            resource_governor: self.resource_governor.clone().unwrap_or_else(|| {
+                crate::resource_governor::ResourceGovernorManager::spawn(None)
+            }),
+            battery_policy,
+            activity_classifier: crate::activity_classifier::ActivityClassifierManager::spawn(
+                self.db.clone(),
+            ),
+            file_activity: crate::file_activity::FileActivityManager::spawn(self.db.clone()),
+            notification_capture: crate::notification_capture::NotificationCaptureManager::spawn(
+                self.db.clone(),
+            ),
+            auto_retranscribe: crate::auto_retranscribe::AutoRetranscribeManager::spawn(
+                self.db.clone(),
+                self.audio_manager.clone(),
+            ),
+            media_migration: crate::media_migration::MediaMigrationManager::new(),
+            auth: self.auth.clone(),
         });
 
         let cors = CorsLayer::new()
@@ -383,20 +513,34 @@ impl SCServer {
             ]);
         let server = Server::axum()
             .get("/search", search)
+            .get("/audio/search", audio_search)
             .get("/audio/list", api_list_audio_devices)
             .get("/vision/list", api_list_monitors)
             .post("/tags/vision/batch", get_tags_batch)
             .post("/tags/:content_type/:id", add_tags)
             .delete("/tags/:content_type/:id", remove_tags)
+            .post("/tags/:content_type/range", tag_time_range)
+            .get("/tags/:content_type/:tag_name/ids", list_content_ids_by_tag)
+            .post("/bookmarks", add_bookmark)
+            .get("/bookmarks", list_bookmarks)
+            .delete("/bookmarks/:id", remove_bookmark)
             .get("/frames/:frame_id", get_frame_data)
             .get("/frames/:frame_id/ocr", get_frame_ocr_data)
             .post("/frames/:frame_id/ocr", run_frame_ocr)
             .get("/frames/:frame_id/context", get_frame_context)
+            .get("/frames/:frame_id/elements", get_frame_elements)
             .get("/frames/:frame_id/metadata", get_frame_metadata)
             .get("/frames/next-valid", get_next_valid_frame)
             .get("/health", health_check)
+            .get("/capture-gaps", list_capture_gaps_handler)
+            .get("/frame-codes/search", search_frame_codes_handler)
+            .get("/entities/search", search_entities_handler)
+            .get("/entities", get_entity_handler)
             .post("/raw_sql", execute_raw_sql)
             .post("/add", add_to_database)
+            .get("/usage/summary", get_usage_summary_handler)
+            .get("/speakers", list_speakers_handler)
+            .post("/speakers/hide", hide_speaker_handler)
             .get("/speakers/unnamed", get_unnamed_speakers_handler)
             .post("/speakers/update", update_speaker_handler)
             .get("/speakers/search", search_speakers_handler)
@@ -408,11 +552,17 @@ impl SCServer {
             .post("/speakers/undo-reassign", undo_speaker_reassign_handler)
             .get("/meetings", list_meetings_handler)
             .get("/meetings/:id", get_meeting_handler)
+            .get("/scenes", list_scenes_handler)
+            .get("/scenes/search", search_scenes_handler)
+            .get("/scenes/:id", get_scene_handler)
             .post("/experimental/frames/merge", merge_frames_handler)
             .get("/experimental/validate/media", validate_media_handler)
             .post("/audio/start", start_audio)
             .post("/audio/stop", stop_audio)
             .get("/search/keyword", keyword_search_handler)
+            .get("/search/instant", instant_search_handler)
+            .get("/realtime-context", realtime_context_handler)
+            .get("/context", build_context_handler)
             .post("/audio/device/start", start_audio_device)
             .post("/audio/device/stop", stop_audio_device)
             .route_yaml_spec("/openapi.yaml")
@@ -422,6 +572,8 @@ impl SCServer {
         // Build the main router with all routes
         let router = Router::new()
             .merge(server.into_router())
+            // Prometheus exposition of audio/vision/accessibility/DB pipeline metrics
+            .route("/metrics", get(crate::metrics_exporter::metrics_handler))
             // Cloud Sync API routes
             .route("/sync/init", axum::routing::post(sync_api::sync_init))
             .route("/sync/status", get(sync_api::sync_status))
@@ -436,6 +588,129 @@ impl SCServer {
             .route("/archive/configure", axum::routing::post(crate::archive::archive_configure))
             .route("/archive/status", get(crate::archive::archive_status))
             .route("/archive/run", axum::routing::post(crate::archive::archive_run))
+            // DB backup/restore (not in OpenAPI spec)
+            .route("/backup", axum::routing::post(crate::backup_api::backup_create))
+            .route("/backup/restore", axum::routing::post(crate::backup_api::backup_restore))
+            // Webhook registration and delivery history
+            .route("/webhooks", axum::routing::post(crate::webhooks::register_webhook))
+            .route("/webhooks", get(crate::webhooks::list_webhooks_handler))
+            .route(
+                "/webhooks/:id",
+                axum::routing::delete(crate::webhooks::remove_webhook_handler),
+            )
+            .route(
+                "/webhooks/:id/deliveries",
+                get(crate::webhooks::list_webhook_deliveries_handler),
+            )
+            // P2P sync: pairing and incoming chunk delivery
+            .route("/p2p_sync/peers", axum::routing::post(crate::p2p_sync::register_peer))
+            .route("/p2p_sync/peers", get(crate::p2p_sync::list_peers))
+            .route(
+                "/p2p_sync/peers/:id",
+                axum::routing::delete(crate::p2p_sync::remove_peer),
+            )
+            .route("/p2p_sync/receive", axum::routing::post(crate::p2p_sync::receive_chunk))
+            // Cold storage: mounted-folder media tiering
+            .route("/cold_storage/configure", axum::routing::post(crate::cold_storage::configure))
+            .route("/cold_storage/status", get(crate::cold_storage::status))
+            .route("/cold_storage/run", axum::routing::post(crate::cold_storage::run))
+            // Daily notes exporter (not in OpenAPI spec: template is free-form)
+            .route("/daily_notes/configure", axum::routing::post(crate::daily_notes::configure))
+            .route("/daily_notes/status", get(crate::daily_notes::status))
+            .route("/daily_notes/run", axum::routing::post(crate::daily_notes::run))
+            .route("/daily_digest/configure", axum::routing::post(crate::daily_digest::configure))
+            .route("/daily_digest/status", get(crate::daily_digest::status))
+            .route("/daily_digest/run", axum::routing::post(crate::daily_digest::run))
+            .route("/daily_digest/list", get(crate::daily_digest::list))
+            .route("/topics/configure", axum::routing::post(crate::topic_clustering::configure))
+            .route("/topics/status", get(crate::topic_clustering::status))
+            .route("/topics/run", axum::routing::post(crate::topic_clustering::run))
+            .route("/topics/day", get(crate::topic_clustering::get_day_topics))
+            .route("/voice_activity/heatmap", get(crate::voice_activity::heatmap_handler))
+            .route("/time_alignment/sample", axum::routing::post(crate::time_alignment::record_sample_handler))
+            .route("/time_alignment/calibrations", get(crate::time_alignment::list_calibrations_handler))
+            .route("/time_alignment/canonical", get(crate::time_alignment::to_canonical_handler))
+            .route("/auto_retranscribe/configure", axum::routing::post(crate::auto_retranscribe::configure))
+            .route("/auto_retranscribe/status", get(crate::auto_retranscribe::status))
+            .route("/auto_retranscribe/run", axum::routing::post(crate::auto_retranscribe::run))
+            .route("/media_migration/status", get(crate::media_migration::status))
+            .route("/auth/status", get(crate::auth::status))
+            .route(
+                "/privacy/access-audit",
+                get(crate::access_audit::list_access_audit_handler),
+            )
+            .route("/grants", axum::routing::post(crate::capability_grants::create_grant))
+            .route("/grants", get(crate::capability_grants::list_grants_handler))
+            .route(
+                "/grants/:id",
+                axum::routing::delete(crate::capability_grants::revoke_grant_handler),
+            )
+            .route("/profile/status", get(crate::profile::status))
+            .route("/llm/configure", axum::routing::post(crate::llm::configure))
+            .route("/llm/status", get(crate::llm::status))
+            .route("/v1/ask", axum::routing::post(crate::llm::ask))
+            .route(
+                "/usage/configure",
+                axum::routing::post(crate::usage_accounting::configure),
+            )
+            .route("/usage/spend", get(crate::usage_accounting::spend))
+            .route("/watchdog/status", get(crate::watchdog::status))
+            .route(
+                "/watchdog/configure",
+                axum::routing::post(crate::watchdog::configure),
+            )
+            .route("/governor/status", get(crate::resource_governor::status))
+            .route(
+                "/governor/configure",
+                axum::routing::post(crate::resource_governor::configure),
+            )
+            .route(
+                "/battery-policy/status",
+                get(crate::battery_policy::status),
+            )
+            .route(
+                "/battery-policy/configure",
+                axum::routing::post(crate::battery_policy::configure),
+            )
+            .route(
+                "/activity-classifier/status",
+                get(crate::activity_classifier::status),
+            )
+            .route(
+                "/activity-classifier/configure",
+                axum::routing::post(crate::activity_classifier::configure),
+            )
+            .route("/file-activity/status", get(crate::file_activity::status))
+            .route(
+                "/file-activity/configure",
+                axum::routing::post(crate::file_activity::configure),
+            )
+            .route("/file-activity/list", get(crate::file_activity::list))
+            .route(
+                "/notifications/status",
+                get(crate::notification_capture::status),
+            )
+            .route(
+                "/notifications/configure",
+                axum::routing::post(crate::notification_capture::configure),
+            )
+            .route(
+                "/notifications/list",
+                get(crate::notification_capture::list),
+            )
+            .route("/tasks", get(list_tasks).post(create_task))
+            .route(
+                "/tasks/:id",
+                get(get_task).patch(update_task).delete(delete_task),
+            )
+            // Data directory migration: move the DB + media to a new storage root
+            .route("/data_dir/migrate", axum::routing::post(crate::data_dir_migration::migrate))
+            .route("/data_dir/migrate/status", get(crate::data_dir_migration::migrate_status))
+            // Onboarding diagnostics (not in OpenAPI spec: mirrors /vision/status)
+            .route("/doctor", get(crate::doctor::doctor_handler))
+            // Import external recordings into the normal tables (not in OpenAPI spec:
+            // requires a live audio_manager, same treatment as /data_dir/migrate)
+            .route("/import", axum::routing::post(crate::import::import))
             // Vision status endpoint (not in OpenAPI spec to avoid oasgen registration issues)
             .route("/vision/status", get(api_vision_status))
             // Vision pipeline metrics (not in OpenAPI spec)
@@ -449,6 +724,19 @@ impl SCServer {
             .route(
                 "/audio/retranscribe",
                 axum::routing::post(crate::routes::retranscribe::retranscribe_handler),
+            )
+            .route(
+                "/audio/transcribe_now/:chunk_id",
+                axum::routing::post(crate::routes::transcribe_priority::transcribe_now_handler),
+            )
+            .route(
+                "/audio/export",
+                axum::routing::post(crate::routes::audio_export::export_audio_handler),
+            )
+            .route(
+                "/audio/vocabulary",
+                get(crate::routes::vocabulary::get_vocabulary_handler)
+                    .post(crate::routes::vocabulary::set_vocabulary_handler),
             );
 
         // Apple Intelligence — generic OpenAI-compatible endpoint (macOS only)
@@ -500,17 +788,34 @@ impl SCServer {
             router
         };
 
+        let app_state_for_counter = app_state.clone();
+        let app_state_for_access_audit = app_state.clone();
+
         // NOTE: websockets and sse is not supported by openapi so we move it down here
         router
             .route("/stream/frames", get(stream_frames_handler))
+            .route("/sse/events", get(sse_events_handler))
             .route("/ws/events", get(ws_events_handler))
             .route("/ws/health", get(ws_health_handler))
             .route("/ws/metrics", get(ws_metrics_handler))
+            .route("/ws/captions", get(ws_captions_handler))
             .route("/frames/export", get(handle_video_export_ws).post(handle_video_export_post))
             .with_state(app_state.clone())
             .layer(axum::middleware::from_fn(
                 move |req: axum::extract::Request, next: axum::middleware::Next| {
-                    let counter = app_state.api_request_count.clone();
+                    let state = app_state_for_access_audit.clone();
+                    async move { crate::access_audit::track(state, req, next).await }
+                },
+            ))
+            .layer(axum::middleware::from_fn(
+                move |req: axum::extract::Request, next: axum::middleware::Next| {
+                    let state = app_state.clone();
+                    async move { crate::auth::enforce(state, req, next).await }
+                },
+            ))
+            .layer(axum::middleware::from_fn(
+                move |req: axum::extract::Request, next: axum::middleware::Next| {
+                    let counter = app_state_for_counter.api_request_count.clone();
                     async move {
                         counter.fetch_add(1, Ordering::Relaxed);
                         next.run(req).await
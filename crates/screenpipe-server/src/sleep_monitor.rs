@@ -8,18 +8,23 @@
 //! (catches Cmd+Ctrl+Q, menu lock, hot corner, auto-lock, display sleep).
 //! Also listens for NSWorkspace sleep/wake notifications for the `RECENTLY_WOKE` flag.
 //! Windows: polls `OpenInputDesktop` every 5s.
+//! Linux: polls the session's `org.freedesktop.ScreenSaver` D-Bus interface
+//! (`GetActive`) every 5s via `busctl`, which every logind-based desktop
+//! (GNOME, KDE, XFCE) implements for screensaver/lock state.
 //! Exposes an `screen_is_locked()` flag so capture loops can skip work while
 //! the screen is locked / screensaver is active.
 
 use std::sync::atomic::{AtomicBool, Ordering};
 #[cfg(target_os = "macos")]
 use std::time::Duration;
-#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
 use tracing::debug;
 #[cfg(target_os = "macos")]
 use tracing::{debug, error, info, warn};
 #[cfg(target_os = "windows")]
 use tracing::info;
+#[cfg(target_os = "linux")]
+use tracing::{info, warn};
 
 #[cfg(target_os = "macos")]
 use crate::analytics::capture_event_nonblocking;
@@ -326,10 +331,81 @@ pub fn start_sleep_monitor() {
     });
 }
 
-/// No-op on platforms other than macOS and Windows
-#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+/// Start the screen-lock monitor on Linux.
+///
+/// Spawns a background thread that polls the session's
+/// `org.freedesktop.ScreenSaver` D-Bus interface every 5 seconds via
+/// `busctl` (present on any systemd/logind desktop — GNOME, KDE, XFCE all
+/// implement this interface for their lock screen). Requires `busctl` to be
+/// on PATH; if it's missing, polling silently reports "unlocked" so capture
+/// isn't blocked, and a warning is logged once.
+#[cfg(target_os = "linux")]
+pub fn start_sleep_monitor() {
+    info!("Starting Linux screen-lock monitor (org.freedesktop.ScreenSaver polling)");
+
+    std::thread::spawn(|| {
+        let mut warned_missing_busctl = false;
+        loop {
+            let locked = match check_screen_locked_dbus() {
+                Ok(locked) => locked,
+                Err(e) => {
+                    if !warned_missing_busctl {
+                        warn!(
+                            "could not query screensaver state via busctl, assuming unlocked: {}",
+                            e
+                        );
+                        warned_missing_busctl = true;
+                    }
+                    false
+                }
+            };
+
+            let was_locked = SCREEN_IS_LOCKED.swap(locked, Ordering::SeqCst);
+            if locked != was_locked {
+                if locked {
+                    info!("Screen locked (org.freedesktop.ScreenSaver)");
+                } else {
+                    info!("Screen unlocked (org.freedesktop.ScreenSaver)");
+                }
+            }
+
+            std::thread::sleep(std::time::Duration::from_secs(5));
+        }
+    });
+}
+
+/// Query `org.freedesktop.ScreenSaver.GetActive` via `busctl --user`.
+/// Returns `Ok(true)` when the screensaver/lock is active.
+#[cfg(target_os = "linux")]
+fn check_screen_locked_dbus() -> std::io::Result<bool> {
+    let output = std::process::Command::new("busctl")
+        .args([
+            "--user",
+            "call",
+            "org.freedesktop.ScreenSaver",
+            "/org/freedesktop/ScreenSaver",
+            "org.freedesktop.ScreenSaver",
+            "GetActive",
+        ])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(std::io::Error::other(format!(
+            "busctl exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        )));
+    }
+
+    // Expected output on success: "b true" or "b false"
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok(stdout.trim().ends_with("true"))
+}
+
+/// No-op on platforms other than macOS, Windows, and Linux
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
 pub fn start_sleep_monitor() {
-    debug!("Sleep monitor is only available on macOS and Windows");
+    debug!("Sleep monitor is only available on macOS, Windows, and Linux");
 }
 
 #[cfg(test)]
@@ -12,12 +12,103 @@ use screenpipe_accessibility::tree::{
 };
 use screenpipe_accessibility::{UiCaptureConfig, UiRecorder};
 use screenpipe_db::{DatabaseManager, InsertUiEvent};
+
+use crate::hot_frame_cache::{HotFrameCache, HotUiEvent};
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Condvar, Mutex};
 use std::time::Duration;
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
+/// Crash-safe journal for UI events sitting in the in-memory batch, so a
+/// crash or power loss between flushes loses at most one `batch_timeout_ms`
+/// window instead of everything captured since the last successful insert.
+/// Mirrors [`screenpipe_audio::core::spill`]'s write-ahead-then-truncate
+/// approach for audio segments, which already has its own recovery path in
+/// `AudioManager::start_internal` — this is the equivalent for UI events.
+const UI_EVENTS_JOURNAL_FILE: &str = "ui_events_batch.jsonl";
+
+fn ui_events_journal_path(journal_dir: &Path) -> PathBuf {
+    journal_dir.join(UI_EVENTS_JOURNAL_FILE)
+}
+
+/// Append newly-batched events to the journal. Best-effort: a write failure
+/// here must never interrupt UI event capture.
+fn append_journal(journal_dir: &Path, events: &[InsertUiEvent]) {
+    if events.is_empty() {
+        return;
+    }
+    if let Err(e) = std::fs::create_dir_all(journal_dir) {
+        warn!("failed to create UI events journal dir {:?}: {}", journal_dir, e);
+        return;
+    }
+    let path = ui_events_journal_path(journal_dir);
+    let file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path);
+    let mut file = match file {
+        Ok(file) => file,
+        Err(e) => {
+            warn!("failed to open UI events journal {:?}: {}", path, e);
+            return;
+        }
+    };
+    use std::io::Write;
+    for event in events {
+        match serde_json::to_string(event) {
+            Ok(line) => {
+                if let Err(e) = writeln!(file, "{}", line) {
+                    warn!("failed to append to UI events journal {:?}: {}", path, e);
+                    return;
+                }
+            }
+            Err(e) => warn!("failed to serialize UI event for journal: {}", e),
+        }
+    }
+}
+
+/// Truncate the journal once its contents have been safely flushed to the DB.
+fn truncate_journal(journal_dir: &Path) {
+    let _ = std::fs::write(ui_events_journal_path(journal_dir), b"");
+}
+
+/// Recover UI events journaled but never flushed before an unclean shutdown,
+/// insert them directly into `db`, then truncate the journal. Call once at
+/// startup, before normal capture begins.
+async fn replay_journal(db: &Arc<DatabaseManager>, journal_dir: &Path) {
+    let path = ui_events_journal_path(journal_dir);
+    let Ok(contents) = std::fs::read_to_string(&path) else {
+        return;
+    };
+
+    let mut events: Vec<InsertUiEvent> = Vec::new();
+    for line in contents.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str(line) {
+            Ok(event) => events.push(event),
+            Err(e) => warn!("skipping unreadable journaled UI event: {}", e),
+        }
+    }
+
+    if events.is_empty() {
+        return;
+    }
+
+    let n = events.len();
+    match db.insert_ui_events_batch(&events).await {
+        Ok(inserted) => info!(
+            "recovered {} of {} UI events journaled before an unclean shutdown",
+            inserted, n
+        ),
+        Err(e) => error!("failed to recover {} journaled UI events: {}", n, e),
+    }
+    truncate_journal(journal_dir);
+}
+
 /// Shared signal to wake the tree walker thread immediately on app/window switch.
 type WakeSignal = Arc<(Mutex<bool>, Condvar)>;
 
@@ -191,10 +282,17 @@ impl UiRecorderHandle {
 ///
 /// If `capture_trigger_tx` is provided, relevant UI events (app switch, window focus,
 /// click, clipboard) will also be sent as capture triggers for event-driven capture.
+///
+/// `journal_dir` is where in-flight (not yet flushed) events are journaled for crash
+/// recovery — typically `<data_dir>/.ui_events_journal`, a sibling of audio's `.spill`
+/// dir. Any events left over from an unclean shutdown are replayed into the DB before
+/// capture starts.
 pub async fn start_ui_recording(
     db: Arc<DatabaseManager>,
     config: UiRecorderConfig,
     capture_trigger_tx: Option<crate::event_driven_capture::TriggerSender>,
+    journal_dir: PathBuf,
+    hot_frame_cache: Option<Arc<HotFrameCache>>,
 ) -> Result<UiRecorderHandle> {
     if !config.enabled {
         info!("UI event capture is disabled");
@@ -205,6 +303,8 @@ pub async fn start_ui_recording(
         });
     }
 
+    replay_journal(&db, &journal_dir).await;
+
     let ui_config = config.to_ui_config();
     let recorder = UiRecorder::new(ui_config);
 
@@ -234,6 +334,7 @@ pub async fn start_ui_recording(
     let batch_size = config.batch_size;
     let batch_timeout = Duration::from_millis(config.batch_timeout_ms);
     let record_input_events = config.record_input_events;
+    let journal_dir_for_task = journal_dir.clone();
 
     // Start the recording
     let handle = match recorder.start() {
@@ -260,17 +361,74 @@ pub async fn start_ui_recording(
         let mut last_flush = std::time::Instant::now();
         let mut consecutive_failures: u32 = 0;
         let max_batch_age = Duration::from_secs(30); // Drop events older than 30s during storms
+        // Tracks whether a capture_gaps row is currently open for a
+        // user-initiated UI-events pause, so we don't insert a new row on
+        // every loop iteration while the pause is active.
+        let mut pause_gap_open = false;
+        const UI_EVENTS_DEVICE_NAME: &str = "ui_events";
 
         loop {
             if stop_flag_clone.load(Ordering::Relaxed) {
                 break;
             }
 
+            // Skip capture while the user has paused UI-event capture from
+            // the tray/API. Checked every iteration (not just on event
+            // arrival) so the capture_gaps span starts/ends promptly even
+            // during quiet periods with no events.
+            if screenpipe_audio::privacy_pause::is_paused(
+                screenpipe_audio::privacy_pause::CapturePipeline::UiEvents,
+            ) {
+                if !pause_gap_open {
+                    if let Err(e) = db
+                        .open_capture_gap(
+                            UI_EVENTS_DEVICE_NAME,
+                            screenpipe_audio::privacy_pause::PAUSE_GAP_REASON,
+                            chrono::Utc::now(),
+                        )
+                        .await
+                    {
+                        warn!("failed to open capture gap for ui events: {}", e);
+                    }
+                    pause_gap_open = true;
+                }
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                continue;
+            } else if pause_gap_open {
+                if let Err(e) = db
+                    .close_open_capture_gap(UI_EVENTS_DEVICE_NAME, chrono::Utc::now())
+                    .await
+                {
+                    warn!("failed to close capture gap for ui events: {}", e);
+                }
+                pause_gap_open = false;
+            }
+
             // Try to receive events with timeout
             match handle.recv_timeout(Duration::from_millis(100)) {
                 Some(event) => {
                     let db_event = event.to_db_insert(Some(session_id.clone()));
 
+                    // Broadcast live, gated on the same flag that gates
+                    // persistence — /ws/events and /sse/events subscribers
+                    // shouldn't see input events the user opted out of recording.
+                    if record_input_events {
+                        let _ = screenpipe_events::send_event(
+                            "ui_event",
+                            serde_json::json!({
+                                "event_type": db_event.event_type,
+                                "timestamp": db_event.timestamp,
+                                "app_name": db_event.app_name,
+                                "window_title": db_event.window_title,
+                                "x": db_event.x,
+                                "y": db_event.y,
+                                "text_content": db_event.text_content,
+                                "element_role": db_event.element_role,
+                                "element_name": db_event.element_name,
+                            }),
+                        );
+                    }
+
                     // Signal the tree walker to do an immediate walk on app/window switch
                     if matches!(
                         db_event.event_type,
@@ -310,12 +468,24 @@ pub async fn start_ui_recording(
                     }
 
                     if record_input_events {
+                        if let Some(ref cache) = hot_frame_cache {
+                            cache
+                                .push_ui_event(HotUiEvent {
+                                    timestamp: db_event.timestamp,
+                                    event_type: format!("{:?}", db_event.event_type),
+                                    app_name: db_event.app_name.clone(),
+                                    window_title: db_event.window_title.clone(),
+                                    text_content: db_event.text_content.clone(),
+                                })
+                                .await;
+                        }
+                        append_journal(&journal_dir_for_task, std::slice::from_ref(&db_event));
                         batch.push(db_event);
                     }
 
                     // Flush if batch is full
                     if batch.len() >= batch_size {
-                        flush_batch(&db, &mut batch, &mut consecutive_failures).await;
+                        flush_batch(&db, &mut batch, &mut consecutive_failures, &journal_dir_for_task).await;
                         last_flush = std::time::Instant::now();
                     }
                 }
@@ -335,7 +505,7 @@ pub async fn start_ui_recording(
                             );
                         }
 
-                        flush_batch(&db, &mut batch, &mut consecutive_failures).await;
+                        flush_batch(&db, &mut batch, &mut consecutive_failures, &journal_dir_for_task).await;
                         last_flush = std::time::Instant::now();
 
                         // Exponential backoff on consecutive failures
@@ -362,13 +532,14 @@ pub async fn start_ui_recording(
                     batch.len(), last_flush.elapsed().as_secs(), consecutive_failures
                 );
                 batch.clear();
+                truncate_journal(&journal_dir_for_task);
                 last_flush = std::time::Instant::now();
             }
         }
 
         // Final flush
         if !batch.is_empty() {
-            flush_batch(&db, &mut batch, &mut consecutive_failures).await;
+            flush_batch(&db, &mut batch, &mut consecutive_failures, &journal_dir_for_task).await;
         }
 
         handle.stop();
@@ -676,6 +847,7 @@ async fn flush_batch(
     db: &Arc<DatabaseManager>,
     batch: &mut Vec<InsertUiEvent>,
     consecutive_failures: &mut u32,
+    journal_dir: &Path,
 ) {
     if batch.is_empty() {
         return;
@@ -685,6 +857,9 @@ async fn flush_batch(
         Ok(inserted) => {
             debug!("Flushed {} UI events to database", inserted);
             *consecutive_failures = 0;
+            // Everything in this batch made it to the DB — the journal
+            // entries backing it are no longer needed for crash recovery.
+            truncate_journal(journal_dir);
         }
         Err(e) => {
             *consecutive_failures += 1;
@@ -18,6 +18,7 @@ use tracing::{debug, error, info, warn};
 
 use crate::event_driven_capture::{CaptureTrigger, TriggerSender};
 use crate::hot_frame_cache::HotFrameCache;
+use super::capture_settings::{MonitorCaptureRegistry, MonitorCaptureSettings};
 
 /// Configuration for VisionManager
 #[derive(Clone)]
@@ -27,6 +28,8 @@ pub struct VisionManagerConfig {
     pub included_windows: Vec<String>,
     pub vision_metrics: Arc<PipelineMetrics>,
     pub use_pii_removal: bool,
+    /// Whether to persist decoded `otpauth://` (2FA enrollment) QR payloads.
+    pub capture_otp_qr_codes: bool,
 }
 
 /// Status of the VisionManager
@@ -50,6 +53,10 @@ pub struct VisionManager {
     trigger_tx: TriggerSender,
     /// Hot frame cache — capture pushes frames here for zero-DB timeline reads.
     hot_frame_cache: Option<Arc<HotFrameCache>>,
+    /// Per-monitor enable/disable, FPS, and resolution-cap overrides.
+    /// Hot-reloadable via `set_monitor_capture_settings` — shared with any
+    /// future HTTP settings route via `capture_settings()`.
+    capture_settings: Arc<MonitorCaptureRegistry>,
 }
 
 impl VisionManager {
@@ -69,6 +76,9 @@ impl VisionManager {
             recording_tasks: Arc::new(DashMap::new()),
             trigger_tx,
             hot_frame_cache: None,
+            capture_settings: Arc::new(MonitorCaptureRegistry::new(
+                MonitorCaptureSettings::default(),
+            )),
         }
     }
 
@@ -78,6 +88,35 @@ impl VisionManager {
         self
     }
 
+    /// Get a clone of the per-monitor capture settings registry, for
+    /// inspecting or updating enable/disable, FPS, and resolution-cap
+    /// overrides from outside the manager.
+    pub fn capture_settings(&self) -> Arc<MonitorCaptureRegistry> {
+        self.capture_settings.clone()
+    }
+
+    /// Update capture settings for one monitor and, if that monitor is
+    /// currently recording, restart its capture task so the change (FPS,
+    /// resolution cap, enable/disable) takes effect immediately rather than
+    /// waiting for the next process restart.
+    pub async fn set_monitor_capture_settings(
+        &self,
+        monitor_id: u32,
+        settings: MonitorCaptureSettings,
+    ) -> Result<()> {
+        self.capture_settings.set_for_monitor(monitor_id, settings);
+
+        let is_recording = self.recording_tasks.contains_key(&monitor_id);
+        if is_recording {
+            self.stop_monitor(monitor_id).await?;
+        }
+        if settings.enabled && *self.status.read().await == VisionManagerStatus::Running {
+            self.start_monitor(monitor_id).await?;
+        }
+
+        Ok(())
+    }
+
     /// Get a clone of the broadcast trigger sender.
     /// Pass this to `start_ui_recording()` so UI events trigger captures.
     pub fn trigger_sender(&self) -> TriggerSender {
@@ -157,6 +196,11 @@ impl VisionManager {
             return Ok(());
         }
 
+        if !self.capture_settings.settings_for_monitor(monitor_id).enabled {
+            debug!("Monitor {} is disabled in capture settings, skipping", monitor_id);
+            return Ok(());
+        }
+
         // Verify monitor exists
         let monitor = get_monitor_by_id(monitor_id)
             .await
@@ -207,8 +251,15 @@ impl VisionManager {
             ..TreeWalkerConfig::default()
         };
 
-        // Event-driven capture config
-        let capture_config = EventDrivenCaptureConfig::default();
+        // Event-driven capture config, with per-monitor FPS / resolution-cap
+        // overrides applied on top of the defaults.
+        let monitor_settings = self.capture_settings.settings_for_monitor(monitor_id);
+        let mut capture_config = EventDrivenCaptureConfig::default();
+        if let Some(interval_ms) = monitor_settings.min_capture_interval_ms() {
+            capture_config.min_capture_interval_ms = interval_ms;
+        }
+        capture_config.max_capture_dimension = monitor_settings.max_dimension;
+        capture_config.capture_otp_qr_codes = self.config.capture_otp_qr_codes;
 
         // Subscribe to the shared broadcast channel so UI events reach this monitor
         let trigger_rx = self.trigger_tx.subscribe();
@@ -221,6 +221,39 @@ pub struct WindowFilters {
     ignored_urls: HashSet<String>,
 }
 
+/// Composite a monitor-sized frame from `windows` alone, so that anything
+/// outside a valid, non-excluded window's bounds — including whatever's
+/// behind or between windows on the real screen — never appears in the
+/// stored frame. `windows` should already be filtered to the ones that
+/// passed [`WindowFilters::is_valid`] (i.e. exactly what [`get_all_windows`]
+/// plus [`capture_all_visible_windows`]'s filtering already produces).
+///
+/// Windows are blitted in the order given, so later entries paint over
+/// earlier ones where they overlap — callers that care about real stacking
+/// order should sort `windows` topmost-last first. Everything not covered by
+/// a window stays solid black.
+pub fn compose_window_only_frame(
+    monitor_width: u32,
+    monitor_height: u32,
+    monitor_x: i32,
+    monitor_y: i32,
+    windows: &[CapturedWindow],
+) -> DynamicImage {
+    let mut canvas = image::RgbaImage::from_pixel(
+        monitor_width,
+        monitor_height,
+        image::Rgba([0, 0, 0, 255]),
+    );
+
+    for window in windows {
+        let local_x = (window.window_x - monitor_x) as i64;
+        let local_y = (window.window_y - monitor_y) as i64;
+        image::imageops::overlay(&mut canvas, &window.image.to_rgba8(), local_x, local_y);
+    }
+
+    DynamicImage::ImageRgba8(canvas)
+}
+
 impl WindowFilters {
     pub fn new(ignore_list: &[String], include_list: &[String], ignored_urls: &[String]) -> Self {
         Self {
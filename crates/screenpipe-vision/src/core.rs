@@ -6,13 +6,16 @@
 use crate::apple::perform_ocr_apple;
 use crate::capture_screenshot_by_window::CapturedWindow;
 use crate::capture_screenshot_by_window::WindowFilters;
+use crate::capture_screenshot_by_window::compose_window_only_frame;
 use crate::custom_ocr::perform_ocr_custom;
 use crate::frame_comparison::{FrameComparer, FrameComparisonConfig};
+use crate::frame_normalize::{normalize_frame, PerMonitorNormalizeConfig};
 use crate::metrics::PipelineMetrics;
 #[cfg(target_os = "windows")]
 use crate::microsoft::perform_ocr_windows;
 use crate::monitor::get_monitor_by_id;
 use crate::ocr_cache::{WindowCacheKey, WindowOcrCache};
+use crate::redaction::{redact_regions, sensitive_regions};
 use crate::tesseract::perform_ocr_tesseract;
 use crate::utils::{capture_monitor_image, capture_windows, OcrEngine};
 use anyhow::Result;
@@ -21,6 +24,7 @@ use chrono::{DateTime, Utc};
 use image::codecs::jpeg::JpegEncoder;
 use image::DynamicImage;
 use image::GenericImageView;
+use screenpipe_accessibility::tree::TreeWalkerPlatform;
 use screenpipe_core::Language;
 use screenpipe_integrations::unstructured_ocr::perform_ocr_cloud;
 use serde::Deserialize;
@@ -151,12 +155,22 @@ impl std::fmt::Display for ContinuousCaptureError {
 /// Activity feed for adaptive FPS (from screenpipe-accessibility)
 pub type ActivityFeedOption = Option<screenpipe_accessibility::ActivityFeed>;
 
+#[tracing::instrument(skip(
+    result_tx,
+    window_filters,
+    normalize_config,
+    activity_feed,
+    metrics
+))]
 pub async fn continuous_capture(
     result_tx: Sender<RawCaptureResult>,
     interval: Duration,
     monitor_id: u32,
     window_filters: Arc<WindowFilters>,
     capture_unfocused_windows: bool,
+    window_only_capture: bool,
+    normalize_config: Arc<PerMonitorNormalizeConfig>,
+    redact_sensitive_fields: bool,
     activity_feed: ActivityFeedOption,
     metrics: Arc<PipelineMetrics>,
 ) -> Result<(), ContinuousCaptureError> {
@@ -171,6 +185,16 @@ pub async fn continuous_capture(
         ..FrameComparisonConfig::default()
     });
 
+    // Redaction needs the focused window's accessibility tree to find
+    // password/secure-text field bounds — only stood up when enabled, since
+    // walking the AX tree every frame is expensive.
+    let tree_walker = redact_sensitive_fields.then(|| {
+        screenpipe_accessibility::tree::create_tree_walker(
+            screenpipe_accessibility::tree::TreeWalkerConfig::default(),
+        )
+    });
+    let ui_capture_config = screenpipe_accessibility::UiCaptureConfig::new();
+
     // Safety valve: force capture after this duration even if frames are "similar".
     // Prevents the pipeline from going silent when the hash/histogram comparison
     // is too aggressive at low resolution.
@@ -267,6 +291,11 @@ pub async fn continuous_capture(
             }
         };
 
+        // 3b. Normalize DPI scale and HDR-flattening contrast per this monitor's
+        //     config, before comparison so downstream OCR/storage and the
+        //     comparison below always see the same consistent frame.
+        let image = normalize_frame(image, normalize_config.config_for_monitor(monitor_id));
+
         // 4. Optimized frame comparison: downscales once (proportional to preserve
         //    ultrawide aspect ratios), hashes the thumbnail, then compares histograms.
         //    No full-resolution hash or redundant downscale needed.
@@ -316,6 +345,59 @@ pub async fn continuous_capture(
         let window_images =
             capture_windows(&monitor, &window_filters, capture_unfocused_windows).await;
 
+        // 4c. When window-only capture is enabled, replace the raw monitor
+        //     screenshot with a composite built solely from the (already
+        //     filtered) per-window images, so excluded apps' pixels never
+        //     reach OCR, storage, or the recorded video — only the frame
+        //     comparison above still sees the real monitor pixels, since it
+        //     runs before window capture to skip that work on unchanged frames.
+        let image = if window_only_capture {
+            compose_window_only_frame(
+                monitor.width(),
+                monitor.height(),
+                monitor.x(),
+                monitor.y(),
+                &window_images,
+            )
+        } else {
+            image
+        };
+
+        // 4d. Black out password/secure-text field bounds from the focused
+        //     window's accessibility tree, so their pixels never reach OCR,
+        //     storage, or the recorded video.
+        let image = if let Some(walker) = &tree_walker {
+            match walker.walk_focused_window() {
+                Ok(Some(snapshot)) => {
+                    let focused_window = window_images.iter().find(|w| {
+                        w.is_focused
+                            && w.app_name == snapshot.app_name
+                            && w.window_name == snapshot.window_name
+                    });
+                    match focused_window {
+                        Some(window) => {
+                            let regions = sensitive_regions(
+                                window,
+                                monitor.x(),
+                                monitor.y(),
+                                &snapshot.nodes,
+                                &ui_capture_config,
+                            );
+                            redact_regions(image, &regions)
+                        }
+                        None => image,
+                    }
+                }
+                Ok(None) => image,
+                Err(e) => {
+                    debug!("accessibility tree walk failed, skipping redaction: {}", e);
+                    image
+                }
+            }
+        } else {
+            image
+        };
+
         // Send raw capture result (OCR happens in separate worker)
         // Wrap image in Arc to avoid expensive full-bitmap clones downstream.
         // The image is never mutated after capture — all consumers only read it.
@@ -361,6 +443,7 @@ pub struct OcrTaskResult {
     pub cache_misses: u64,
 }
 
+#[tracing::instrument(skip(raw, ocr_engine, languages, ocr_cache), fields(frame_number = raw.frame_number))]
 pub async fn process_ocr_task(
     raw: &RawCaptureResult,
     ocr_engine: &OcrEngine,
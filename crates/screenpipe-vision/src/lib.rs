@@ -4,16 +4,28 @@
 
 #[cfg(target_os = "macos")]
 pub mod apple;
+pub mod code_detect;
+pub use code_detect::{detect_codes, DetectedCode};
 pub mod core;
 pub mod custom_ocr;
 pub mod frame_comparison;
+pub mod frame_normalize;
+pub use frame_normalize::{normalize_frame, NormalizeConfig, PerMonitorNormalizeConfig};
 #[cfg(target_os = "windows")]
 pub mod microsoft;
 pub mod monitor;
 pub use monitor::MonitorListError;
 pub mod metrics;
 pub mod ocr_cache;
+pub mod ocr_provider;
+pub use ocr_provider::{OcrProvider, OcrProviderRegistry, OcrProviderResult, RapidOcrProvider, TesseractProvider};
+pub mod redaction;
+pub use redaction::{redact_regions, sensitive_regions, RedactionRegion};
+pub mod region_diff;
+pub use region_diff::{changed_regions, merge_cached_and_new_ocr, ChangedRegion, RegionDiffConfig};
 pub mod tesseract;
+pub mod thumbnail;
+pub use thumbnail::{tier_path, write_tier};
 pub mod utils;
 #[cfg(target_os = "macos")]
 pub use apple::perform_ocr_apple;
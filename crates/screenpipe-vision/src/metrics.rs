@@ -59,6 +59,14 @@ pub struct PipelineMetrics {
     /// Unix timestamp (secs) of most recent capture attempt — heartbeat proving the loop is alive
     /// even when DB writes time out or are slow.
     pub last_capture_attempt_ts: AtomicU64,
+
+    // --- Region-of-interest OCR (see `region_diff`) ---
+    /// Frames OCR'd via region-of-interest (partial-frame) capture rather than full-frame.
+    pub roi_frames_processed: AtomicU64,
+    /// Total pixel area across all ROI frames (width * height of the whole frame).
+    pub roi_pixels_total: AtomicU64,
+    /// Total pixel area actually re-OCR'd (sum of changed regions' area) across ROI frames.
+    pub roi_pixels_ocred: AtomicU64,
 }
 
 impl PipelineMetrics {
@@ -81,6 +89,9 @@ impl PipelineMetrics {
             pipeline_stall_count: AtomicU64::new(0),
             last_db_write_ts: AtomicU64::new(0),
             last_capture_attempt_ts: AtomicU64::new(0),
+            roi_frames_processed: AtomicU64::new(0),
+            roi_pixels_total: AtomicU64::new(0),
+            roi_pixels_ocred: AtomicU64::new(0),
         }
     }
 
@@ -160,6 +171,16 @@ impl PipelineMetrics {
         self.video_queue_depth.store(video, Ordering::Relaxed);
     }
 
+    /// Record one region-of-interest OCR pass: the whole frame's pixel area
+    /// versus the area actually re-OCR'd (the changed regions' area).
+    pub fn record_roi_ocr(&self, pixels_total: u64, pixels_ocred: u64) {
+        self.roi_frames_processed.fetch_add(1, Ordering::Relaxed);
+        self.roi_pixels_total
+            .fetch_add(pixels_total, Ordering::Relaxed);
+        self.roi_pixels_ocred
+            .fetch_add(pixels_ocred, Ordering::Relaxed);
+    }
+
     /// Take a snapshot of all metrics for reporting.
     pub fn snapshot(&self) -> MetricsSnapshot {
         let frames_captured = self.frames_captured.load(Ordering::Relaxed);
@@ -212,6 +233,16 @@ impl PipelineMetrics {
             pipeline_stall_count: self.pipeline_stall_count.load(Ordering::Relaxed),
             last_db_write_ts: self.last_db_write_ts.load(Ordering::Relaxed),
             last_capture_attempt_ts: self.last_capture_attempt_ts.load(Ordering::Relaxed),
+            roi_frames_processed: self.roi_frames_processed.load(Ordering::Relaxed),
+            roi_pixels_skipped_pct: {
+                let total = self.roi_pixels_total.load(Ordering::Relaxed);
+                let ocred = self.roi_pixels_ocred.load(Ordering::Relaxed);
+                if total > 0 {
+                    1.0 - (ocred as f64 / total as f64)
+                } else {
+                    0.0
+                }
+            },
         }
     }
 }
@@ -248,4 +279,8 @@ pub struct MetricsSnapshot {
     pub last_db_write_ts: u64,
     /// Unix timestamp (secs) of most recent capture attempt (0 = none yet)
     pub last_capture_attempt_ts: u64,
+    /// Frames OCR'd via region-of-interest capture instead of full-frame
+    pub roi_frames_processed: u64,
+    /// Fraction of ROI frames' pixel area skipped by reusing cached text (0.0-1.0)
+    pub roi_pixels_skipped_pct: f64,
 }
@@ -11,7 +11,7 @@ mod tests {
     use std::{path::PathBuf, time::Duration as StdDuration, time::Instant};
     use tokio::sync::{mpsc, Mutex};
 
-    use screenpipe_vision::{continuous_capture, PipelineMetrics};
+    use screenpipe_vision::{continuous_capture, PerMonitorNormalizeConfig, PipelineMetrics};
     use std::time::Duration;
     use tokio::time::timeout;
 
@@ -82,7 +82,10 @@ mod tests {
             monitor,
             window_filters,
             save_text_files_flag,
-            None, // activity_feed
+            false, // window_only_capture
+            Arc::new(PerMonitorNormalizeConfig::default()),
+            false, // redact_sensitive_fields
+            None,  // activity_feed
             metrics,
         ));
 